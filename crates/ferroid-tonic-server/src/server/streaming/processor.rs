@@ -32,6 +32,8 @@ use tonic::Status;
 /// - Batches IDs into fixed-size chunks and sends them through `chunk_tx`.
 /// - Exits early if the output channel is closed.
 /// - Uses cooperative yielding (`yield_now`) when generation is pending.
+/// - Sends an `OUT_OF_RANGE` status and exits if the clock has passed the
+///   layout's maximum timestamp.
 #[allow(clippy::needless_pass_by_ref_mut)]
 #[allow(clippy::used_underscore_binding)]
 pub async fn handle_stream_request(
@@ -78,6 +80,17 @@ pub async fn handle_stream_request(
                 // Yield to the scheduler to avoid busy looping.
                 tokio::task::yield_now().await;
             }
+            Poll::TimestampOverflow { timestamp } => {
+                // The clock has outlived the layout; retrying cannot succeed.
+                #[cfg(feature = "tracing")]
+                tracing::error!("Worker {_worker_id} timestamp {timestamp} overflowed the layout");
+                let status = Status::out_of_range(format!(
+                    "clock timestamp {timestamp} exceeds the ID layout's maximum timestamp"
+                ));
+                let _ = chunk_tx.send(Err(status)).await;
+                *buff_pos = 0;
+                return;
+            }
         }
     }
 
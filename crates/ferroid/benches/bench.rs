@@ -1,4 +1,9 @@
-use core::{fmt, hint::black_box};
+use core::{
+    cmp, fmt,
+    hint::black_box,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use std::{thread, time::Instant};
 
 use criterion::{
     Criterion, Throughput, async_executor::SmolExecutor, criterion_group, criterion_main,
@@ -8,8 +13,8 @@ use ferroid::{
     define_snowflake_id,
     futures::{SmolSleep, SnowflakeGeneratorAsyncExt, TokioSleep, UlidGeneratorAsyncExt},
     generator::{
-        AtomicMonoUlidGenerator, AtomicSnowflakeGenerator, BasicMonoUlidGenerator,
-        BasicSnowflakeGenerator, BasicUlidGenerator, LockMonoUlidGenerator, LockSnowflakeGenerator,
+        AtomicSnowflakeGenerator, BasicMonoUlidGenerator, BasicSnowflakeGenerator,
+        BasicUlidGenerator, LockMonoUlidGenerator, LockSnowflakeGenerator, Poll,
         SnowflakeGenerator, UlidGenerator, thread_local::Ulid,
     },
     id::{BeBytes, SnowflakeId, SnowflakeTwitterId, ULID, UlidId},
@@ -18,6 +23,9 @@ use ferroid::{
 };
 use tokio::runtime::Builder;
 
+#[cfg(target_has_atomic = "128")]
+use ferroid::generator::AtomicMonoUlidGenerator;
+
 define_snowflake_id!(
     /// A snowflake that contains enough sequence bits to test the hot path
     BenchSnowflake, u64,
@@ -27,6 +35,87 @@ define_snowflake_id!(
     sequence: 63
 );
 
+/// `BenchSnowflake` has no timestamp bits, so a clock pinned at zero keeps
+/// every call on the sequence-increment hot path instead of overflowing.
+#[derive(Clone, Copy, Default)]
+struct ZeroClock;
+
+impl TimeSource<u64> for ZeroClock {
+    fn current_millis(&self) -> u64 {
+        0
+    }
+}
+
+/// The compare-and-swap-only atomic generator that `AtomicSnowflakeGenerator`
+/// used before sequences were reserved with `fetch_add`, kept as a baseline
+/// for the contention benchmarks.
+struct CasSnowflakeGenerator<ID, T>
+where
+    ID: SnowflakeId<Ty = u64>,
+    T: TimeSource<u64>,
+{
+    state: AtomicU64,
+    time: T,
+    _id: core::marker::PhantomData<ID>,
+}
+
+impl<ID, T> SnowflakeGenerator<ID, T> for CasSnowflakeGenerator<ID, T>
+where
+    ID: SnowflakeId<Ty = u64>,
+    T: TimeSource<u64>,
+{
+    type Err = core::convert::Infallible;
+
+    fn new(machine_id: u64, time: T) -> Self {
+        Self {
+            state: AtomicU64::new(ID::from_components(0, machine_id, 0).to_raw()),
+            time,
+            _id: core::marker::PhantomData,
+        }
+    }
+
+    fn try_next_id(&self, mut f: impl FnMut(u64)) -> Result<ID, Self::Err> {
+        loop {
+            match self.try_poll_id()? {
+                Poll::Ready { id } => break Ok(id),
+                Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => {
+                    panic!("timestamp {timestamp} overflows the layout")
+                }
+            }
+        }
+    }
+
+    fn try_poll_id(&self) -> Result<Poll<ID>, Self::Err> {
+        let now = self.time.current_millis();
+        let current_raw = self.state.load(Ordering::Relaxed);
+        let current_id = ID::from_raw(current_raw);
+
+        let next_id = match now.cmp(&current_id.timestamp()) {
+            cmp::Ordering::Equal if current_id.has_sequence_room() => {
+                current_id.increment_sequence()
+            }
+            cmp::Ordering::Greater => current_id.rollover_to_timestamp(now),
+            _ => return Ok(Poll::Pending { yield_for: 1 }),
+        };
+
+        if self
+            .state
+            .compare_exchange(
+                current_raw,
+                next_id.to_raw(),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+        {
+            Ok(Poll::Ready { id: next_id })
+        } else {
+            Ok(Poll::Pending { yield_for: 0 })
+        }
+    }
+}
+
 fn bench_snow_base32<ID>(c: &mut Criterion, group_name: &str)
 where
     ID: SnowflakeId + Base32SnowExt + fmt::Display,
@@ -185,6 +274,42 @@ fn bench_generator_snow<ID, G, T>(
     });
     group.finish();
 }
+/// Measures a single generator shared by `threads` threads, each issuing an
+/// equal share of the iterations.
+fn bench_generator_snow_contended<ID, G, T>(
+    c: &mut Criterion,
+    group_name: &str,
+    generator_fn: impl Fn(u64, T) -> G,
+    clock_fn: impl Fn() -> T,
+) where
+    G: SnowflakeGenerator<ID, T> + Sync,
+    ID: SnowflakeId,
+    T: TimeSource<ID::Ty>,
+{
+    let mut group = c.benchmark_group(group_name);
+    group.throughput(Throughput::Elements(1));
+    for threads in [1, 4, 16, 64] {
+        group.bench_function(format!("threads/{threads}"), |b| {
+            b.iter_custom(|iters| {
+                let g = generator_fn(0, clock_fn());
+                let per_thread = iters.div_ceil(threads);
+                let start = Instant::now();
+                thread::scope(|s| {
+                    for _ in 0..threads {
+                        s.spawn(|| {
+                            let backoff = |_| core::hint::spin_loop();
+                            for _ in 0..per_thread {
+                                black_box(g.try_next_id(backoff).ok());
+                            }
+                        });
+                    }
+                });
+                start.elapsed()
+            });
+        });
+    }
+    group.finish();
+}
 fn bench_generator_ulid<ID, G, T, R>(
     c: &mut Criterion,
     group_name: &str,
@@ -354,6 +479,7 @@ fn benchmark_ulid(c: &mut Criterion) {
         MonotonicClock::default,
         ThreadRandom::default,
     );
+    #[cfg(target_has_atomic = "128")]
     bench_generator_ulid::<ULID, _, _, _>(
         c,
         "ulid/atomic_mono",
@@ -363,24 +489,39 @@ fn benchmark_ulid(c: &mut Criterion) {
     );
 }
 fn benchmark_snow(c: &mut Criterion) {
-    // These use `BenchSnowflake` to avoid pending
+    // These use `BenchSnowflake` and `ZeroClock` to avoid pending
     bench_generator_snow::<BenchSnowflake, _, _>(
         c,
         "snow/basic",
         BasicSnowflakeGenerator::new,
-        MonotonicClock::default,
+        ZeroClock::default,
     );
     bench_generator_snow::<BenchSnowflake, _, _>(
         c,
         "snow/lock",
         LockSnowflakeGenerator::new,
-        MonotonicClock::default,
+        ZeroClock::default,
     );
     bench_generator_snow::<BenchSnowflake, _, _>(
         c,
         "snow/atomic",
         AtomicSnowflakeGenerator::new,
-        MonotonicClock::default,
+        ZeroClock::default,
+    );
+}
+fn benchmark_snow_contended(c: &mut Criterion) {
+    // `ZeroClock` keeps every thread racing for sequences within one tick
+    bench_generator_snow_contended::<BenchSnowflake, _, _>(
+        c,
+        "snow/atomic_cas/contended",
+        CasSnowflakeGenerator::new,
+        ZeroClock::default,
+    );
+    bench_generator_snow_contended::<BenchSnowflake, _, _>(
+        c,
+        "snow/atomic/contended",
+        AtomicSnowflakeGenerator::new,
+        ZeroClock::default,
     );
 }
 fn benchmark_async_ulid(c: &mut Criterion) {
@@ -398,6 +539,7 @@ fn benchmark_async_ulid(c: &mut Criterion) {
         MonotonicClock::default,
         ThreadRandom::default,
     );
+    #[cfg(target_has_atomic = "128")]
     bench_async_ulid_tokio::<ULID, _, _, _>(
         c,
         "ulid/atomic_mono/tokio",
@@ -405,6 +547,7 @@ fn benchmark_async_ulid(c: &mut Criterion) {
         MonotonicClock::default,
         ThreadRandom::default,
     );
+    #[cfg(target_has_atomic = "128")]
     bench_async_ulid_smol::<ULID, _, _, _>(
         c,
         "ulid/atomic_mono/smol",
@@ -418,25 +561,25 @@ fn benchmark_async_snow(c: &mut Criterion) {
         c,
         "snow/lock/tokio",
         LockSnowflakeGenerator::new,
-        MonotonicClock::default,
+        ZeroClock::default,
     );
     bench_async_snow_smol::<BenchSnowflake, _, _>(
         c,
         "snow/lock/smol",
         LockSnowflakeGenerator::new,
-        MonotonicClock::default,
+        ZeroClock::default,
     );
     bench_async_snow_tokio::<BenchSnowflake, _, _>(
         c,
         "snow/atomic/tokio",
         AtomicSnowflakeGenerator::new,
-        MonotonicClock::default,
+        ZeroClock::default,
     );
     bench_async_snow_smol::<BenchSnowflake, _, _>(
         c,
         "snow/atomic/smol",
         AtomicSnowflakeGenerator::new,
-        MonotonicClock::default,
+        ZeroClock::default,
     );
 }
 criterion_group!(
@@ -463,6 +606,7 @@ criterion_group!(
         bench_base32_snow,
          // --- Snowflake Synchronous Generation ---
         benchmark_snow,
+        benchmark_snow_contended,
         // --- Snowflake Async Generation ---
         benchmark_async_snow,
 );
@@ -1,9 +1,9 @@
-use core::{convert::Infallible, future::Future, time::Duration};
+use core::{convert::Infallible, future::Future};
 
 use super::SleepProvider;
 use crate::{
-    generator::{Poll, Result, SnowflakeGenerator},
-    id::{SnowflakeId, ToU64},
+    generator::{Poll, Result, SnowflakeGenerator, timestamp_overflow},
+    id::SnowflakeId,
     time::TimeSource,
 };
 
@@ -75,9 +75,8 @@ where
         loop {
             let dur = match self.try_poll_id()? {
                 Poll::Ready { id } => break Ok(id),
-                Poll::Pending { yield_for } => {
-                    Duration::from_millis(yield_for.to_u64().saturating_mul(T::GRANULARITY_MILLIS))
-                }
+                Poll::Pending { yield_for } => T::units_to_duration(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
             };
             S::sleep_for(dur).await;
         }
@@ -89,6 +88,7 @@ mod tests {
     use core::{
         pin::pin,
         task::{Context, Poll as TaskPoll, RawWaker, RawWakerVTable, Waker},
+        time::Duration,
     };
     use std::sync::{
         Mutex,
@@ -123,6 +123,20 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct MicrosStepTime {
+        reads: AtomicUsize,
+    }
+
+    impl TimeSource<u64> for MicrosStepTime {
+        const GRANULARITY_MILLIS: u64 = 0;
+        const GRANULARITY_NANOS: u64 = 1_000;
+
+        fn current_millis(&self) -> u64 {
+            self.reads.fetch_add(1, Ordering::Relaxed) as u64
+        }
+    }
+
     fn block_on<F: Future>(future: F) -> F::Output {
         fn raw_waker() -> RawWaker {
             fn clone(_: *const ()) -> RawWaker {
@@ -172,5 +186,24 @@ mod tests {
 
         assert_eq!(*LAST_SLEEP.lock().unwrap(), Some(Duration::from_millis(8)));
         assert_eq!(id.timestamp(), 1);
+
+        let generator: LockSnowflakeGenerator<SnowflakeTwitterId, _> =
+            LockSnowflakeGenerator::from_components(
+                0,
+                0,
+                SnowflakeTwitterId::max_sequence(),
+                MicrosStepTime::default(),
+            );
+
+        let id = block_on(
+            <LockSnowflakeGenerator<SnowflakeTwitterId, _> as SnowflakeGeneratorAsyncExt<
+                SnowflakeTwitterId,
+                _,
+            >>::try_next_id_async::<RecordingSleep>(&generator),
+        )
+        .unwrap();
+
+        assert_eq!(*LAST_SLEEP.lock().unwrap(), Some(Duration::from_micros(1)));
+        assert_eq!(id.timestamp(), 1);
     }
 }
@@ -1,9 +1,9 @@
-use core::{convert::Infallible, future::Future, time::Duration};
+use core::{convert::Infallible, future::Future};
 
 use super::SleepProvider;
 use crate::{
-    generator::{Poll, Result, UlidGenerator},
-    id::{ToU64, UlidId},
+    generator::{Poll, Result, UlidGenerator, timestamp_overflow},
+    id::UlidId,
     rand::RandSource,
     time::TimeSource,
 };
@@ -77,9 +77,8 @@ where
         loop {
             let dur = match self.try_poll_id()? {
                 Poll::Ready { id } => break Ok(id),
-                Poll::Pending { yield_for } => {
-                    Duration::from_millis(yield_for.to_u64().saturating_mul(T::GRANULARITY_MILLIS))
-                }
+                Poll::Pending { yield_for } => T::units_to_duration(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
             };
             S::sleep_for(dur).await;
         }
@@ -91,6 +90,7 @@ mod tests {
     use core::{
         pin::pin,
         task::{Context, Poll as TaskPoll, RawWaker, RawWakerVTable, Waker},
+        time::Duration,
     };
     use std::sync::{
         Mutex,
@@ -125,6 +125,20 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct MicrosStepTime {
+        reads: AtomicUsize,
+    }
+
+    impl TimeSource<u128> for MicrosStepTime {
+        const GRANULARITY_MILLIS: u64 = 0;
+        const GRANULARITY_NANOS: u64 = 1_000;
+
+        fn current_millis(&self) -> u128 {
+            self.reads.fetch_add(1, Ordering::Relaxed) as u128
+        }
+    }
+
     #[derive(Clone, Copy)]
     struct ZeroRand;
 
@@ -183,5 +197,24 @@ mod tests {
 
         assert_eq!(*LAST_SLEEP.lock().unwrap(), Some(Duration::from_millis(8)));
         assert_eq!(id.timestamp(), 1);
+
+        let generator: LockMonoUlidGenerator<ULID, _, _> = LockMonoUlidGenerator::from_components(
+            0,
+            ULID::max_random(),
+            MicrosStepTime::default(),
+            ZeroRand,
+        );
+
+        let id = block_on(
+            <LockMonoUlidGenerator<ULID, _, _> as UlidGeneratorAsyncExt<
+                ULID,
+                _,
+                _,
+            >>::try_next_id_async::<RecordingSleep>(&generator),
+        )
+        .unwrap();
+
+        assert_eq!(*LAST_SLEEP.lock().unwrap(), Some(Duration::from_micros(1)));
+        assert_eq!(id.timestamp(), 1);
     }
 }
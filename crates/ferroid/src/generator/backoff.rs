@@ -0,0 +1,256 @@
+use core::time::Duration;
+
+/// A strategy for waiting while a generator is pending.
+///
+/// Generators report how long to wait in the time source's native units
+/// (`yield_for`). The `*_with_backoff` methods on [`SnowflakeGenerator`] and
+/// [`UlidGenerator`] translate those units into a real [`Duration`] using
+/// [`TimeSource::units_to_duration`] before calling [`Backoff::wait`], so
+/// strategies never need to know the clock's granularity.
+///
+/// A `yield_for` of zero means the generator lost a race to another thread and
+/// can be retried immediately.
+///
+/// Any `FnMut(Duration)` closure is also a [`Backoff`].
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     generator::{BasicSnowflakeGenerator, SnowflakeGenerator, SpinBackoff},
+///     id::SnowflakeTwitterId,
+///     time::{MonotonicClock, TWITTER_EPOCH},
+/// };
+///
+/// let generator =
+///     BasicSnowflakeGenerator::new(0, MonotonicClock::<1>::with_epoch(TWITTER_EPOCH));
+///
+/// let id: SnowflakeTwitterId = generator.next_id_with_backoff(&mut SpinBackoff);
+/// ```
+///
+/// [`SnowflakeGenerator`]: crate::generator::SnowflakeGenerator
+/// [`UlidGenerator`]: crate::generator::UlidGenerator
+/// [`TimeSource::units_to_duration`]: crate::time::TimeSource::units_to_duration
+pub trait Backoff {
+    /// Waits before the generator is polled again.
+    ///
+    /// `duration` is how long the generator expects to remain pending.
+    fn wait(&mut self, duration: Duration);
+
+    /// Resets any accumulated state after an ID was generated.
+    fn reset(&mut self) {}
+}
+
+impl<F> Backoff for F
+where
+    F: FnMut(Duration),
+{
+    fn wait(&mut self, duration: Duration) {
+        self(duration);
+    }
+}
+
+/// Busy-waits with a CPU spin hint and retries immediately.
+///
+/// Lowest latency, but burns a core while the generator is pending. Suited to
+/// `no_std` targets and short, rare waits.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SpinBackoff;
+
+impl Backoff for SpinBackoff {
+    fn wait(&mut self, _duration: Duration) {
+        core::hint::spin_loop();
+    }
+}
+
+/// Yields the current thread to the OS scheduler and retries.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct YieldBackoff;
+
+#[cfg(feature = "std")]
+impl Backoff for YieldBackoff {
+    fn wait(&mut self, _duration: Duration) {
+        std::thread::yield_now();
+    }
+}
+
+/// Sleeps for exactly as long as the generator asks.
+///
+/// Retries after a zero duration (a lost race) yield instead of sleeping.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SleepBackoff;
+
+#[cfg(feature = "std")]
+impl Backoff for SleepBackoff {
+    fn wait(&mut self, duration: Duration) {
+        if duration.is_zero() {
+            std::thread::yield_now();
+        } else {
+            std::thread::sleep(duration);
+        }
+    }
+}
+
+/// Sleeps for an exponentially growing delay, capped at a maximum.
+///
+/// Each consecutive wait sleeps for the larger of the requested duration and
+/// the current delay (never more than `max`), then doubles the delay. The delay
+/// returns to `initial` once an ID is generated. This spreads out retries when
+/// many threads contend on a shared generator.
+///
+/// # Example
+/// ```
+/// use core::time::Duration;
+///
+/// use ferroid::{
+///     generator::{AtomicSnowflakeGenerator, ExponentialBackoff, SnowflakeGenerator},
+///     id::SnowflakeTwitterId,
+///     time::{MonotonicClock, TWITTER_EPOCH},
+/// };
+///
+/// let generator =
+///     AtomicSnowflakeGenerator::new(0, MonotonicClock::<1>::with_epoch(TWITTER_EPOCH));
+/// let mut backoff =
+///     ExponentialBackoff::new(Duration::from_micros(10), Duration::from_millis(1));
+///
+/// let id: SnowflakeTwitterId = generator.next_id_with_backoff(&mut backoff);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+pub struct ExponentialBackoff {
+    initial: Duration,
+    max: Duration,
+    current: Duration,
+}
+
+#[cfg(feature = "std")]
+impl ExponentialBackoff {
+    /// Creates a backoff starting at `initial` and doubling up to `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `initial` is greater than `max`.
+    #[must_use]
+    pub const fn new(initial: Duration, max: Duration) -> Self {
+        assert!(
+            initial.as_nanos() <= max.as_nanos(),
+            "initial delay must not exceed the maximum delay"
+        );
+        Self {
+            initial,
+            max,
+            current: initial,
+        }
+    }
+
+    /// Returns the delay used for the next wait (before honoring the requested
+    /// duration).
+    #[must_use]
+    pub const fn current(&self) -> Duration {
+        self.current
+    }
+
+    fn next_delay(&mut self, duration: Duration) -> Duration {
+        let delay = duration.max(self.current).min(self.max);
+        self.current = self.current.saturating_mul(2).min(self.max);
+        delay
+    }
+}
+
+#[cfg(feature = "std")]
+impl Backoff for ExponentialBackoff {
+    fn wait(&mut self, duration: Duration) {
+        let delay = self.next_delay(duration);
+        if delay.is_zero() {
+            std::thread::yield_now();
+        } else {
+            std::thread::sleep(delay);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.current = self.initial;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponential_backoff_doubles_up_to_max_and_resets() {
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_millis(1), Duration::from_millis(5));
+
+        assert_eq!(backoff.next_delay(Duration::ZERO), Duration::from_millis(1));
+        assert_eq!(backoff.next_delay(Duration::ZERO), Duration::from_millis(2));
+        assert_eq!(backoff.next_delay(Duration::ZERO), Duration::from_millis(4));
+        assert_eq!(backoff.next_delay(Duration::ZERO), Duration::from_millis(5));
+        assert_eq!(backoff.next_delay(Duration::ZERO), Duration::from_millis(5));
+
+        backoff.reset();
+        assert_eq!(backoff.current(), Duration::from_millis(1));
+
+        // A longer requested wait wins, but never beyond the cap.
+        assert_eq!(
+            backoff.next_delay(Duration::from_millis(3)),
+            Duration::from_millis(3)
+        );
+        assert_eq!(
+            backoff.next_delay(Duration::from_secs(1)),
+            Duration::from_millis(5)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "initial delay must not exceed the maximum delay")]
+    fn exponential_backoff_rejects_initial_above_max() {
+        let _ = ExponentialBackoff::new(Duration::from_millis(2), Duration::from_millis(1));
+    }
+
+    #[cfg(all(feature = "snowflake", feature = "basic"))]
+    #[test]
+    fn with_backoff_translates_units_through_the_time_source() {
+        use core::cell::Cell;
+        use std::{rc::Rc, vec, vec::Vec};
+
+        use crate::{
+            generator::{BasicSnowflakeGenerator, SnowflakeGenerator},
+            id::SnowflakeTwitterId,
+            time::TimeSource,
+        };
+
+        #[derive(Clone)]
+        struct CoarseTime(Rc<Cell<u64>>);
+        impl TimeSource<u64> for CoarseTime {
+            const GRANULARITY_MILLIS: u64 = 8;
+
+            fn current_millis(&self) -> u64 {
+                self.0.get()
+            }
+        }
+
+        let now = Rc::new(Cell::new(0));
+        let generator: BasicSnowflakeGenerator<SnowflakeTwitterId, _> =
+            BasicSnowflakeGenerator::from_components(
+                0,
+                0,
+                SnowflakeTwitterId::max_sequence(),
+                CoarseTime(Rc::clone(&now)),
+            );
+
+        let mut waits = Vec::new();
+        let id = generator.next_id_with_backoff(&mut |dur| {
+            waits.push(dur);
+            now.set(1);
+        });
+
+        assert_eq!(waits, vec![Duration::from_millis(8)]);
+        assert_eq!(id.timestamp(), 1);
+        assert_eq!(id.sequence(), 0);
+    }
+}
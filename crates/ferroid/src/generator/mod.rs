@@ -1,16 +1,52 @@
+mod backoff;
 mod error;
 #[cfg(any(feature = "lock", feature = "parking-lot"))]
 mod mutex;
 #[cfg(feature = "snowflake")]
 mod snowflake;
+#[cfg(all(
+    any(feature = "snowflake", feature = "ulid"),
+    any(
+        feature = "basic",
+        feature = "lock",
+        feature = "atomic",
+        feature = "critical-section"
+    )
+))]
+mod stats;
 mod status;
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-local")))]
+#[cfg(feature = "thread-local")]
+pub mod thread_local;
 #[cfg(feature = "ulid")]
 mod ulid;
+pub use backoff::*;
 pub use error::*;
 #[cfg(any(feature = "lock", feature = "parking-lot"))]
 pub use mutex::*;
 #[cfg(feature = "snowflake")]
 pub use snowflake::*;
+#[cfg(all(
+    any(feature = "snowflake", feature = "ulid"),
+    any(
+        feature = "basic",
+        feature = "lock",
+        feature = "atomic",
+        feature = "critical-section"
+    )
+))]
+pub(crate) use stats::Counters;
+#[cfg(all(
+    feature = "stats",
+    any(feature = "snowflake", feature = "ulid"),
+    any(
+        feature = "basic",
+        feature = "lock",
+        feature = "atomic",
+        feature = "critical-section"
+    )
+))]
+pub use stats::GeneratorStats;
 pub use status::*;
 #[cfg(feature = "ulid")]
 pub use ulid::*;
@@ -1,28 +1,117 @@
 use core::{cmp, marker::PhantomData};
 
-use portable_atomic::{AtomicU64, Ordering};
+#[cfg(target_has_atomic = "32")]
+use portable_atomic::AtomicU32;
+#[cfg(target_has_atomic = "64")]
+use portable_atomic::AtomicU64;
+#[cfg(target_has_atomic = "128")]
+use portable_atomic::AtomicU128;
+use portable_atomic::Ordering;
 #[cfg(feature = "tracing")]
 use tracing::instrument;
 
 use crate::{
-    generator::{Poll, Result, SnowflakeGenerator},
+    generator::{Counters, Poll, Result, SnowflakeGenerator, advance_target, timestamp_overflow},
     id::SnowflakeId,
     time::TimeSource,
 };
 
+/// An integer type with a native atomic counterpart that can hold the state of
+/// an [`AtomicSnowflakeGenerator`].
+///
+/// Implemented for [`u32`], [`u64`], and [`u128`] on targets that support
+/// atomics of the corresponding width (`target_has_atomic`), backed by
+/// [`portable_atomic`]'s `AtomicU32`, `AtomicU64`, and `AtomicU128`.
+pub trait AtomicStorage: Copy {
+    /// The atomic type storing a value of `Self`.
+    type Atomic: Send + Sync;
+
+    /// Creates a new atomic initialized to `value`.
+    fn new_atomic(value: Self) -> Self::Atomic;
+
+    /// Loads the current value.
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+
+    /// Stores `new` if the current value equals `current`, returning the
+    /// previous value on success and the observed value on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns the observed value if it differs from `current`.
+    fn compare_exchange(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> core::result::Result<Self, Self>;
+
+    /// Adds `value` to the current value, wrapping on overflow, and returns
+    /// the previous value.
+    fn fetch_add(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+}
+
+macro_rules! impl_atomic_storage {
+    ($int:ty, $atomic:ty) => {
+        impl AtomicStorage for $int {
+            type Atomic = $atomic;
+
+            #[inline]
+            fn new_atomic(value: Self) -> Self::Atomic {
+                <$atomic>::new(value)
+            }
+
+            #[inline]
+            fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                atomic.load(order)
+            }
+
+            #[inline]
+            fn compare_exchange(
+                atomic: &Self::Atomic,
+                current: Self,
+                new: Self,
+                success: Ordering,
+                failure: Ordering,
+            ) -> core::result::Result<Self, Self> {
+                atomic.compare_exchange(current, new, success, failure)
+            }
+
+            #[inline]
+            fn fetch_add(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                atomic.fetch_add(value, order)
+            }
+        }
+    };
+}
+
+#[cfg(target_has_atomic = "32")]
+impl_atomic_storage!(u32, AtomicU32);
+#[cfg(target_has_atomic = "64")]
+impl_atomic_storage!(u64, AtomicU64);
+#[cfg(target_has_atomic = "128")]
+impl_atomic_storage!(u128, AtomicU128);
+
 /// A lock-free Snowflake ID generator suitable for multi-threaded environments.
 ///
-/// This generator stores the Snowflake state in an [`AtomicU64`], allowing safe
-/// shared use across threads.
+/// This generator stores the Snowflake state in a single atomic integer of the
+/// same width as the ID (see [`AtomicStorage`]), allowing safe shared use
+/// across threads.
+///
+/// Within a tick, sequence numbers are claimed with a single `fetch_add`, so
+/// concurrent callers never lose a race to one another; only the first caller
+/// of a new tick performs a compare-and-swap to roll the state over. When a
+/// tick's sequence is exhausted, late `fetch_add`s may carry past the sequence
+/// field. Such claims are rejected and the state is reset at the next tick.
 ///
 /// ## Features
 /// - ✅ Thread-safe
-/// - ❌ Safely implement any [`SnowflakeId`] layout
+/// - ✅ Supports [`u32`], [`u64`], and [`u128`] [`SnowflakeId`] layouts
 ///
 /// ## Caveats
-/// This implementation uses an [`AtomicU64`] internally, so it only supports ID
-/// layouts where the underlying type is [`u64`]. You cannot use layouts with
-/// larger or smaller representations (i.e., `ID::Ty` must be [`u64`]).
+/// The backing integer must have a native atomic on the target: `u32` layouts
+/// need `target_has_atomic = "32"`, `u64` layouts need `target_has_atomic =
+/// "64"`, and `u128` layouts need `target_has_atomic = "128"`.
 ///
 /// ## Recommended When
 /// - You're in a multi-threaded environment
@@ -36,20 +125,24 @@ use crate::{
 /// [`LockSnowflakeGenerator`]: crate::generator::LockSnowflakeGenerator
 pub struct AtomicSnowflakeGenerator<ID, T>
 where
-    ID: SnowflakeId<Ty = u64>,
+    ID: SnowflakeId,
+    ID::Ty: AtomicStorage,
     T: TimeSource<ID::Ty>,
 {
     #[cfg(feature = "cache-padded")]
-    state: crossbeam_utils::CachePadded<AtomicU64>,
+    state: crossbeam_utils::CachePadded<<ID::Ty as AtomicStorage>::Atomic>,
     #[cfg(not(feature = "cache-padded"))]
-    state: AtomicU64,
+    state: <ID::Ty as AtomicStorage>::Atomic,
+    machine_id: ID::Ty,
     time: T,
+    stats: Counters,
     _id: PhantomData<ID>,
 }
 
 impl<ID, T> AtomicSnowflakeGenerator<ID, T>
 where
-    ID: SnowflakeId<Ty = u64>,
+    ID: SnowflakeId,
+    ID::Ty: AtomicStorage,
     T: TimeSource<ID::Ty>,
 {
     /// Creates a new [`AtomicSnowflakeGenerator`] initialized with the current
@@ -119,10 +212,12 @@ where
         let initial = ID::from_components(timestamp, machine_id, sequence);
         Self {
             #[cfg(feature = "cache-padded")]
-            state: crossbeam_utils::CachePadded::new(AtomicU64::new(initial.to_raw())),
+            state: crossbeam_utils::CachePadded::new(ID::Ty::new_atomic(initial.to_raw())),
             #[cfg(not(feature = "cache-padded"))]
-            state: AtomicU64::new(initial.to_raw()),
+            state: ID::Ty::new_atomic(initial.to_raw()),
+            machine_id: initial.machine_id(),
             time,
+            stats: Counters::new(),
             _id: PhantomData,
         }
     }
@@ -149,6 +244,7 @@ where
             match self.poll_id() {
                 Poll::Ready { id } => break id,
                 Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
             }
         }
     }
@@ -157,7 +253,8 @@ where
     ///
     /// Returns a new, time-ordered, unique ID if generation succeeds. If the
     /// generator is temporarily exhausted (e.g., the sequence is full and the
-    /// time has not advanced, or CAS fails), it returns [`Poll::Pending`].
+    /// time has not advanced, or another thread is rolling the state over to a
+    /// new tick), it returns [`Poll::Pending`].
     ///
     /// # Example
     /// ```
@@ -174,6 +271,7 @@ where
     ///     match generator.poll_id() {
     ///         Poll::Ready { id } => break id,
     ///         Poll::Pending { .. } => std::thread::yield_now(),
+    ///         Poll::TimestampOverflow { .. } => panic!("clock overflowed the ID layout"),
     ///     }
     /// };
     /// ```
@@ -181,54 +279,148 @@ where
     pub fn poll_id(&self) -> Poll<ID> {
         let now = self.time.current_millis();
 
-        let current_raw = self.state.load(Ordering::Relaxed);
+        let current_raw = ID::Ty::load(&self.state, Ordering::Relaxed);
         let current_id = ID::from_raw(current_raw);
         let current_ts = current_id.timestamp();
 
-        let next_id = match now.cmp(&current_ts) {
-            cmp::Ordering::Equal => {
-                if current_id.has_sequence_room() {
-                    current_id.increment_sequence()
-                } else {
-                    return Poll::Pending { yield_for: ID::ONE };
-                }
+        match now.cmp(&current_ts) {
+            cmp::Ordering::Equal => self.reserve(current_id, now),
+            cmp::Ordering::Greater if now > ID::max_timestamp() => {
+                Poll::TimestampOverflow { timestamp: now }
             }
-            cmp::Ordering::Greater => current_id.rollover_to_timestamp(now),
-            cmp::Ordering::Less => {
-                return Self::cold_clock_behind(now, current_ts);
+            cmp::Ordering::Greater => self.rollover(current_raw, now),
+            cmp::Ordering::Less => self.cold_clock_behind(now, current_ts),
+        }
+    }
+
+    /// Claims the next sequence number of the current tick with `fetch_add`.
+    #[inline]
+    fn reserve(&self, current_id: ID, now: ID::Ty) -> Poll<ID> {
+        if !current_id.has_sequence_room() || current_id.machine_id() != self.machine_id {
+            self.stats.exhausted();
+            return Poll::Pending { yield_for: ID::ONE };
+        }
+
+        let prev = ID::from_raw(ID::Ty::fetch_add(&self.state, ID::ONE, Ordering::Relaxed));
+        if prev.machine_id() != self.machine_id || !prev.has_sequence_room() {
+            // Other callers drained the tick first; our increment carried past
+            // the sequence field and is discarded at the next rollover.
+            self.stats.exhausted();
+            Poll::Pending { yield_for: ID::ONE }
+        } else if prev.timestamp() < now {
+            // The carry wrapped the timestamp field. Never reissue old IDs.
+            self.stats.contention();
+            Poll::Pending {
+                yield_for: ID::ZERO,
             }
-        };
+        } else {
+            self.stats.issued();
+            Poll::Ready {
+                id: prev.increment_sequence(),
+            }
+        }
+    }
 
+    /// Moves the state to a new tick with a compare-and-swap.
+    #[inline]
+    fn rollover(&self, current_raw: ID::Ty, now: ID::Ty) -> Poll<ID> {
+        let next_id = ID::from_components(now, self.machine_id, ID::ZERO);
         let next_raw = next_id.to_raw();
 
-        if self
-            .state
-            .compare_exchange(current_raw, next_raw, Ordering::Relaxed, Ordering::Relaxed)
+        // Comparing raw values keeps a state that carried into higher fields
+        // from ever being rolled back.
+        if next_raw > current_raw
+            && ID::Ty::compare_exchange(
+                &self.state,
+                current_raw,
+                next_raw,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
             .is_ok()
         {
+            self.stats.issued();
             Poll::Ready { id: next_id }
         } else {
             // CAS failed - another thread won the race. Yield 0 to retry
             // immediately.
+            self.stats.contention();
             Poll::Pending {
                 yield_for: ID::ZERO,
             }
         }
     }
 
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`.
+    ///
+    /// See [`BasicSnowflakeGenerator::advance_to`] for details. Concurrent
+    /// calls to [`Self::poll_id`] either observe the raised state or win the
+    /// race, in which case this retries against their newer state.
+    ///
+    /// [`BasicSnowflakeGenerator::advance_to`]: crate::generator::BasicSnowflakeGenerator::advance_to
+    pub fn advance_to(&self, id: ID) {
+        let mut current_raw = ID::Ty::load(&self.state, Ordering::Relaxed);
+        loop {
+            let current = ID::from_raw(current_raw);
+            let target = advance_target(id, self.machine_id);
+            if target <= current {
+                return;
+            }
+            match ID::Ty::compare_exchange(
+                &self.state,
+                current_raw,
+                target.to_raw(),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(observed) => current_raw = observed,
+            }
+        }
+    }
+
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    ///
+    /// Once a tick's sequence is exhausted, this returns the tick's last
+    /// possible ID even if a concurrent caller is still waiting for it.
+    pub fn last_id(&self) -> ID {
+        let id = ID::from_raw(ID::Ty::load(&self.state, Ordering::Relaxed));
+        if id.machine_id() == self.machine_id {
+            id
+        } else {
+            // Reservations carried past an exhausted sequence.
+            ID::from_components(id.timestamp(), self.machine_id, ID::max_sequence())
+        }
+    }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
+
     #[cold]
     #[inline(never)]
-    fn cold_clock_behind(now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
+    fn cold_clock_behind(&self, now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
         let yield_for = current_ts - now;
         debug_assert!(yield_for >= ID::ZERO);
+        self.stats.clock_behind(yield_for);
         Poll::Pending { yield_for }
     }
 }
 
 impl<ID, T> SnowflakeGenerator<ID, T> for AtomicSnowflakeGenerator<ID, T>
 where
-    ID: SnowflakeId<Ty = u64>,
-    T: TimeSource<u64>,
+    ID: SnowflakeId,
+    ID::Ty: AtomicStorage,
+    T: TimeSource<ID::Ty>,
 {
     type Err = core::convert::Infallible;
 
@@ -4,7 +4,7 @@ use core::{cell::Cell, cmp::Ordering};
 use tracing::instrument;
 
 use crate::{
-    generator::{Poll, Result, SnowflakeGenerator},
+    generator::{Counters, Poll, Result, SnowflakeGenerator, advance_target, timestamp_overflow},
     id::SnowflakeId,
     time::TimeSource,
 };
@@ -35,6 +35,7 @@ where
 {
     state: Cell<ID>,
     time: T,
+    stats: Counters,
 }
 
 impl<ID, T> BasicSnowflakeGenerator<ID, T>
@@ -110,6 +111,7 @@ where
         Self {
             state: Cell::new(id),
             time,
+            stats: Counters::new(),
         }
     }
 
@@ -135,6 +137,7 @@ where
             match self.poll_id() {
                 Poll::Ready { id } => break id,
                 Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
             }
         }
     }
@@ -160,6 +163,7 @@ where
     ///     match generator.poll_id() {
     ///         Poll::Ready { id } => break id,
     ///         Poll::Pending { .. } => std::thread::yield_now(),
+    ///         Poll::TimestampOverflow { .. } => panic!("clock overflowed the ID layout"),
     ///     }
     /// };
     /// ```
@@ -174,25 +178,83 @@ where
                 if state.has_sequence_room() {
                     let updated = state.increment_sequence();
                     self.state.set(updated);
+                    self.stats.issued();
                     Poll::Ready { id: updated }
                 } else {
+                    self.stats.exhausted();
                     Poll::Pending { yield_for: ID::ONE }
                 }
             }
+            Ordering::Greater if now > ID::max_timestamp() => {
+                Poll::TimestampOverflow { timestamp: now }
+            }
             Ordering::Greater => {
                 let updated = state.rollover_to_timestamp(now);
                 self.state.set(updated);
+                self.stats.issued();
                 Poll::Ready { id: updated }
             }
-            Ordering::Less => Self::cold_clock_behind(now, current_ts),
+            Ordering::Less => self.cold_clock_behind(now, current_ts),
+        }
+    }
+
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`.
+    ///
+    /// This is meant for resuming after a failover, e.g. from `MAX(id)` read
+    /// back from a database. The state never moves backwards, so an older `id`
+    /// is a no-op. `id` may carry another machine ID; subsequent IDs keep this
+    /// generator's machine ID and still sort after it. If `id` is ahead of the
+    /// time source, the generator returns [`Poll::Pending`] until the clock
+    /// catches up.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::BasicSnowflakeGenerator,
+    ///     id::{SnowflakeId, SnowflakeTwitterId},
+    /// };
+    ///
+    /// let generator = BasicSnowflakeGenerator::<SnowflakeTwitterId, _>::new(1, || 100_u64);
+    ///
+    /// // `MAX(id)` read back from the database after a failover.
+    /// let max_id = SnowflakeTwitterId::from_components(100, 0, 42);
+    /// generator.advance_to(max_id);
+    ///
+    /// let id = generator.next_id(|_| std::thread::yield_now());
+    /// assert!(id > max_id);
+    /// ```
+    pub fn advance_to(&self, id: ID) {
+        let current = self.state.get();
+        let target = advance_target(id, current.machine_id());
+        if target > current {
+            self.state.set(target);
         }
     }
 
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    pub fn last_id(&self) -> ID {
+        self.state.get()
+    }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
+
     #[cold]
     #[inline(never)]
-    fn cold_clock_behind(now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
+    fn cold_clock_behind(&self, now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
         let yield_for = current_ts - now;
         debug_assert!(yield_for >= ID::ZERO);
+        self.stats.clock_behind(yield_for);
         Poll::Pending { yield_for }
     }
 }
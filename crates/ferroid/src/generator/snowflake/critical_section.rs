@@ -0,0 +1,306 @@
+use core::{cell::Cell, cmp::Ordering};
+
+use critical_section::Mutex;
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+use crate::{
+    generator::{Counters, Poll, Result, SnowflakeGenerator, advance_target, timestamp_overflow},
+    id::SnowflakeId,
+    time::TimeSource,
+};
+
+/// A Snowflake ID generator that guards its state with a
+/// [`critical_section`], for sharing across cores without `std` or an
+/// allocator.
+///
+/// The state lives inline in a [`critical_section::Mutex`], so the generator
+/// can be placed in a `static` and used from every core and interrupt handler.
+/// The time source is read outside the critical section, which only spans the
+/// state update.
+///
+/// The final binary must provide a critical-section implementation, e.g. from
+/// a HAL, a `cortex-m` single-core feature, or `critical-section/std` on
+/// hosted targets.
+///
+/// ## Features
+/// - ✅ Thread-safe
+/// - ✅ Safely implement any [`SnowflakeId`] layout
+/// - ✅ `no_std` without `alloc`
+///
+/// ## Recommended When
+/// - You're on a `no_std` multi-core target or in a kernel
+/// - You need shared access without `std` or an allocator
+/// - Your target doesn't support atomics wide enough for the ID
+///
+/// ## See Also
+/// - [`BasicSnowflakeGenerator`]
+/// - [`LockSnowflakeGenerator`]
+/// - [`AtomicSnowflakeGenerator`]
+///
+/// [`BasicSnowflakeGenerator`]: crate::generator::BasicSnowflakeGenerator
+/// [`LockSnowflakeGenerator`]: crate::generator::LockSnowflakeGenerator
+/// [`AtomicSnowflakeGenerator`]: crate::generator::AtomicSnowflakeGenerator
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     generator::CriticalSectionSnowflakeGenerator,
+///     id::SnowflakeTwitterId,
+///     time::{MonotonicClock, TWITTER_EPOCH},
+/// };
+///
+/// let generator: CriticalSectionSnowflakeGenerator<SnowflakeTwitterId, _> =
+///     CriticalSectionSnowflakeGenerator::new(0, MonotonicClock::<1>::with_epoch(TWITTER_EPOCH));
+///
+/// std::thread::scope(|scope| {
+///     for _ in 0..4 {
+///         scope.spawn(|| generator.next_id(|_| std::thread::yield_now()));
+///     }
+/// });
+/// ```
+pub struct CriticalSectionSnowflakeGenerator<ID, T>
+where
+    ID: SnowflakeId,
+    T: TimeSource<ID::Ty>,
+{
+    state: Mutex<Cell<ID>>,
+    time: T,
+    stats: Counters,
+}
+
+impl<ID, T> CriticalSectionSnowflakeGenerator<ID, T>
+where
+    ID: SnowflakeId,
+    T: TimeSource<ID::Ty>,
+{
+    /// Creates a new [`CriticalSectionSnowflakeGenerator`] initialized with the
+    /// current time and a given machine ID.
+    ///
+    /// This constructor sets the initial timestamp and sequence to zero, and
+    /// uses the provided `time` to fetch the current time during ID generation.
+    /// It is the recommended way to create a new generator for typical use
+    /// cases.
+    ///
+    /// # Parameters
+    ///
+    /// - `machine_id`: A unique identifier for the node or instance generating
+    ///   IDs. This value will be encoded into every generated ID.
+    /// - `time`: A [`TimeSource`] implementation (e.g., [`MonotonicClock`])
+    ///   that determines how timestamps are generated.
+    ///
+    /// # Returns
+    ///
+    /// A new [`CriticalSectionSnowflakeGenerator`] ready to produce unique,
+    /// time-ordered IDs.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::{CriticalSectionSnowflakeGenerator, Poll},
+    ///     id::SnowflakeTwitterId,
+    ///     time::{MonotonicClock, TWITTER_EPOCH},
+    /// };
+    ///
+    /// let generator =
+    ///     CriticalSectionSnowflakeGenerator::new(0, MonotonicClock::<1>::with_epoch(TWITTER_EPOCH));
+    ///
+    /// let id: SnowflakeTwitterId = generator.next_id(|_| std::thread::yield_now());
+    /// ```
+    ///
+    /// [`TimeSource`]: crate::time::TimeSource
+    /// [`MonotonicClock`]: crate::time::MonotonicClock
+    pub fn new(machine_id: ID::Ty, time: T) -> Self {
+        Self::from_components(ID::ZERO, machine_id, ID::ZERO, time)
+    }
+
+    /// Creates a new ID generator from explicit component values.
+    ///
+    /// This constructor is primarily useful for advanced use cases such as
+    /// restoring state from persistent storage or controlling the starting
+    /// point of the generator manually.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The initial timestamp component (usually in time-source units)
+    /// - `machine_id`: The machine or worker identifier
+    /// - `sequence`: The initial sequence number
+    /// - `time`: A [`TimeSource`] implementation used to fetch the current time
+    ///
+    /// # Returns
+    /// A new generator instance preloaded with the given state.
+    ///
+    /// # ⚠️ Note
+    /// In typical use cases, you should prefer [`Self::new`] to let the
+    /// generator initialize itself from the current time.
+    pub fn from_components(
+        timestamp: ID::Ty,
+        machine_id: ID::Ty,
+        sequence: ID::Ty,
+        time: T,
+    ) -> Self {
+        let id = ID::from_components(timestamp, machine_id, sequence);
+        Self {
+            state: Mutex::new(Cell::new(id)),
+            time,
+            stats: Counters::new(),
+        }
+    }
+
+    /// Generates a new ID.
+    ///
+    /// Returns a new, time-ordered, unique ID.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::{CriticalSectionSnowflakeGenerator, Poll},
+    ///     id::SnowflakeTwitterId,
+    ///     time::MonotonicClock,
+    /// };
+    ///
+    /// let generator = CriticalSectionSnowflakeGenerator::new(0, MonotonicClock::default());
+    ///
+    /// let id: SnowflakeTwitterId = generator.next_id(|_| std::thread::yield_now());
+    /// ```
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self, f)))]
+    pub fn next_id(&self, mut f: impl FnMut(ID::Ty)) -> ID {
+        loop {
+            match self.poll_id() {
+                Poll::Ready { id } => break id,
+                Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
+            }
+        }
+    }
+
+    /// Attempts to generate the next available ID.
+    ///
+    /// Returns a new, time-ordered, unique ID if generation succeeds. If the
+    /// generator is temporarily exhausted (e.g., the sequence is full and the
+    /// time has not advanced), it returns [`Poll::Pending`].
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::{CriticalSectionSnowflakeGenerator, Poll},
+    ///     id::SnowflakeTwitterId,
+    ///     time::{MonotonicClock, TWITTER_EPOCH},
+    /// };
+    ///
+    /// let generator =
+    ///     CriticalSectionSnowflakeGenerator::new(0, MonotonicClock::<1>::with_epoch(TWITTER_EPOCH));
+    ///
+    /// let id: SnowflakeTwitterId = loop {
+    ///     match generator.poll_id() {
+    ///         Poll::Ready { id } => break id,
+    ///         Poll::Pending { .. } => std::thread::yield_now(),
+    ///         Poll::TimestampOverflow { .. } => panic!("clock overflowed the ID layout"),
+    ///     }
+    /// };
+    /// ```
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self)))]
+    pub fn poll_id(&self) -> Poll<ID> {
+        let now = self.time.current_millis();
+
+        critical_section::with(|cs| {
+            let cell = self.state.borrow(cs);
+            let state = cell.get();
+            let current_ts = state.timestamp();
+
+            match now.cmp(&current_ts) {
+                Ordering::Equal => {
+                    if state.has_sequence_room() {
+                        let updated = state.increment_sequence();
+                        cell.set(updated);
+                        self.stats.issued();
+                        Poll::Ready { id: updated }
+                    } else {
+                        self.stats.exhausted();
+                        Poll::Pending { yield_for: ID::ONE }
+                    }
+                }
+                Ordering::Greater if now > ID::max_timestamp() => {
+                    Poll::TimestampOverflow { timestamp: now }
+                }
+                Ordering::Greater => {
+                    let updated = state.rollover_to_timestamp(now);
+                    cell.set(updated);
+                    self.stats.issued();
+                    Poll::Ready { id: updated }
+                }
+                Ordering::Less => self.cold_clock_behind(now, current_ts),
+            }
+        })
+    }
+
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`.
+    ///
+    /// See [`BasicSnowflakeGenerator::advance_to`] for details.
+    ///
+    /// [`BasicSnowflakeGenerator::advance_to`]: crate::generator::BasicSnowflakeGenerator::advance_to
+    pub fn advance_to(&self, id: ID) {
+        critical_section::with(|cs| {
+            let cell = self.state.borrow(cs);
+            let current = cell.get();
+            let target = advance_target(id, current.machine_id());
+            if target > current {
+                cell.set(target);
+            }
+        });
+    }
+
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    pub fn last_id(&self) -> ID {
+        critical_section::with(|cs| self.state.borrow(cs).get())
+    }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn cold_clock_behind(&self, now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
+        let yield_for = current_ts - now;
+        debug_assert!(yield_for >= ID::ZERO);
+        self.stats.clock_behind(yield_for);
+        Poll::Pending { yield_for }
+    }
+}
+
+impl<ID, T> SnowflakeGenerator<ID, T> for CriticalSectionSnowflakeGenerator<ID, T>
+where
+    ID: SnowflakeId,
+    T: TimeSource<ID::Ty>,
+{
+    type Err = core::convert::Infallible;
+
+    fn new(machine_id: ID::Ty, time: T) -> Self {
+        Self::new(machine_id, time)
+    }
+
+    fn next_id(&self, f: impl FnMut(ID::Ty)) -> ID {
+        self.next_id(f)
+    }
+
+    fn try_next_id(&self, f: impl FnMut(ID::Ty)) -> Result<ID, Self::Err> {
+        Ok(self.next_id(f))
+    }
+
+    fn poll_id(&self) -> Poll<ID> {
+        self.poll_id()
+    }
+
+    fn try_poll_id(&self) -> Result<Poll<ID>, Self::Err> {
+        Ok(self.poll_id())
+    }
+}
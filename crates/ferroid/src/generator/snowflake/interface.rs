@@ -1,7 +1,7 @@
 use core::fmt;
 
 use crate::{
-    generator::{Poll, Result},
+    generator::{Backoff, Poll, Result, timestamp_overflow},
     id::SnowflakeId,
     time::TimeSource,
 };
@@ -26,6 +26,11 @@ where
     /// - the newly generated ID, or
     /// - a duration to yield/sleep if the generator must wait for the time
     ///   source to advance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator reports [`Poll::TimestampOverflow`], since
+    /// waiting cannot bring the clock back within the layout.
     fn next_id(&self, f: impl FnMut(ID::Ty)) -> ID
     where
         Self::Err: Into<core::convert::Infallible>,
@@ -51,6 +56,11 @@ where
     ///
     /// May return an error if the underlying implementation uses a lock and it
     /// is poisoned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator reports [`Poll::TimestampOverflow`], since
+    /// waiting cannot bring the clock back within the layout.
     fn try_next_id(&self, f: impl FnMut(ID::Ty)) -> Result<ID, Self::Err>;
 
     /// Attempts to generate the next available ID.
@@ -87,4 +97,74 @@ where
     /// May return an error if the underlying implementation uses a lock and it
     /// is poisoned.
     fn try_poll_id(&self) -> Result<Poll<ID>, Self::Err>;
+
+    /// Generates the next available ID, waiting with `backoff` while the
+    /// generator is pending.
+    ///
+    /// This is the infallible counterpart to
+    /// [`SnowflakeGenerator::try_next_id_with_backoff`]. Each `yield_for` is converted into a
+    /// real [`Duration`] using [`TimeSource::units_to_duration`], and the
+    /// backoff is reset once an ID is produced.
+    ///
+    /// [`Duration`]: core::time::Duration
+    fn next_id_with_backoff(&self, backoff: &mut impl Backoff) -> ID
+    where
+        Self::Err: Into<core::convert::Infallible>,
+    {
+        match self.try_next_id_with_backoff(backoff) {
+            Ok(id) => id,
+            Err(e) => {
+                #[allow(unreachable_code)]
+                // `into()` satisfies the trait bound at compile time.
+                match e.into() {}
+            }
+        }
+    }
+
+    /// Generates the next available ID with fallible error handling, waiting
+    /// with `backoff` while the generator is pending.
+    ///
+    /// Each `yield_for` is converted into a real [`Duration`] using
+    /// [`TimeSource::units_to_duration`], and the backoff is reset once an ID
+    /// is produced.
+    ///
+    /// # Errors
+    ///
+    /// May return an error if the underlying implementation uses a lock and it
+    /// is poisoned.
+    ///
+    /// [`Duration`]: core::time::Duration
+    fn try_next_id_with_backoff(&self, backoff: &mut impl Backoff) -> Result<ID, Self::Err> {
+        loop {
+            match self.try_poll_id()? {
+                Poll::Ready { id } => {
+                    backoff.reset();
+                    break Ok(id);
+                }
+                Poll::Pending { yield_for } => backoff.wait(T::units_to_duration(yield_for)),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
+            }
+        }
+    }
+}
+
+/// Returns the state a generator for `machine_id` must be raised to so that
+/// every ID it issues afterwards is strictly greater than `observed`.
+///
+/// IDs order by timestamp, then machine ID, then sequence. Within `observed`'s
+/// tick, a lower machine ID can never overtake it, so the sequence is pinned to
+/// its maximum and the generator moves on to the next tick.
+#[cfg(any(
+    feature = "basic",
+    feature = "lock",
+    feature = "atomic",
+    feature = "critical-section"
+))]
+pub(crate) fn advance_target<ID: SnowflakeId>(observed: ID, machine_id: ID::Ty) -> ID {
+    let sequence = match observed.machine_id().cmp(&machine_id) {
+        core::cmp::Ordering::Less => ID::ZERO,
+        core::cmp::Ordering::Equal => observed.sequence(),
+        core::cmp::Ordering::Greater => ID::max_sequence(),
+    };
+    ID::from_components(observed.timestamp(), machine_id, sequence)
 }
@@ -5,7 +5,10 @@ use core::cmp::Ordering;
 use tracing::instrument;
 
 use crate::{
-    generator::{Error, Mutex, Poll, Result, SnowflakeGenerator},
+    generator::{
+        Counters, Error, Mutex, Poll, Result, SnowflakeGenerator, advance_target,
+        timestamp_overflow,
+    },
     id::SnowflakeId,
     time::TimeSource,
 };
@@ -41,6 +44,7 @@ where
     #[cfg(not(feature = "cache-padded"))]
     pub(crate) state: Arc<Mutex<ID>>,
     pub(crate) time: T,
+    stats: Counters,
 }
 
 impl<ID, T> LockSnowflakeGenerator<ID, T>
@@ -121,6 +125,7 @@ where
             #[cfg(not(feature = "cache-padded"))]
             state: Arc::new(Mutex::new(id)),
             time,
+            stats: Counters::new(),
         }
     }
 
@@ -181,6 +186,7 @@ where
             match self.try_poll_id()? {
                 Poll::Ready { id } => break Ok(id),
                 Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
             }
         }
     }
@@ -206,6 +212,7 @@ where
     ///     match generator.poll_id() {
     ///         Poll::Ready { id } => break id,
     ///         Poll::Pending { .. } => std::thread::yield_now(),
+    ///         Poll::TimestampOverflow { .. } => panic!("clock overflowed the ID layout"),
     ///     }
     /// };
     /// ```
@@ -242,8 +249,8 @@ where
     /// ```
     /// use ferroid::{
     ///     generator::{LockSnowflakeGenerator, Poll},
-    ///     id::{SnowflakeTwitterId, ToU64},
-    ///     time::{MonotonicClock, TWITTER_EPOCH},
+    ///     id::SnowflakeTwitterId,
+    ///     time::{MonotonicClock, TWITTER_EPOCH, TimeSource},
     /// };
     ///
     /// let generator =
@@ -254,12 +261,10 @@ where
     ///     match generator.try_poll_id() {
     ///         Ok(Poll::Ready { id }) => break id,
     ///         Ok(Poll::Pending { yield_for }) => {
-    ///             std::thread::sleep(core::time::Duration::from_millis(
-    ///                 yield_for
-    ///                     .to_u64()
-    ///                     .saturating_mul(MonotonicClock::<1>::GRANULARITY_MILLIS),
-    ///             ));
+    ///             let dur = <MonotonicClock as TimeSource<u64>>::units_to_duration(yield_for);
+    ///             std::thread::sleep(dur);
     ///         }
+    ///         Ok(Poll::TimestampOverflow { .. }) => panic!("clock overflowed the ID layout"),
     ///         Err(e) => panic!("Generator error: {}", e),
     ///     }
     /// };
@@ -288,24 +293,122 @@ where
             Ordering::Equal => {
                 if id.has_sequence_room() {
                     *id = id.increment_sequence();
+                    self.stats.issued();
                     Ok(Poll::Ready { id: *id })
                 } else {
+                    self.stats.exhausted();
                     Ok(Poll::Pending { yield_for: ID::ONE })
                 }
             }
+            Ordering::Greater if now > ID::max_timestamp() => {
+                Ok(Poll::TimestampOverflow { timestamp: now })
+            }
             Ordering::Greater => {
                 *id = id.rollover_to_timestamp(now);
+                self.stats.issued();
                 Ok(Poll::Ready { id: *id })
             }
-            Ordering::Less => Ok(Self::cold_clock_behind(now, current_ts)),
+            Ordering::Less => Ok(self.cold_clock_behind(now, current_ts)),
+        }
+    }
+
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`.
+    ///
+    /// See [`BasicSnowflakeGenerator::advance_to`] for details.
+    ///
+    /// [`BasicSnowflakeGenerator::advance_to`]: crate::generator::BasicSnowflakeGenerator::advance_to
+    #[cfg(feature = "parking-lot")]
+    pub fn advance_to(&self, id: ID)
+    where
+        Error: Into<core::convert::Infallible>,
+    {
+        if let Err(e) = self.try_advance_to(id) {
+            #[allow(unreachable_code)]
+            // `into()` satisfies the trait bound at compile time.
+            match Into::<core::convert::Infallible>::into(e) {}
         }
     }
 
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`, with fallible error handling.
+    ///
+    /// See [`BasicSnowflakeGenerator::advance_to`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock is poisoned.
+    ///
+    /// [`BasicSnowflakeGenerator::advance_to`]: crate::generator::BasicSnowflakeGenerator::advance_to
+    pub fn try_advance_to(&self, id: ID) -> Result<(), Error> {
+        let mut current = {
+            #[cfg(feature = "parking-lot")]
+            {
+                self.state.lock()
+            }
+            #[cfg(not(feature = "parking-lot"))]
+            {
+                self.state.lock()?
+            }
+        };
+        let target = advance_target(id, current.machine_id());
+        if target > *current {
+            *current = target;
+        }
+        Ok(())
+    }
+
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    #[cfg(feature = "parking-lot")]
+    pub fn last_id(&self) -> ID
+    where
+        Error: Into<core::convert::Infallible>,
+    {
+        match self.try_last_id() {
+            Ok(id) => id,
+            Err(e) => {
+                #[allow(unreachable_code)]
+                // `into()` satisfies the trait bound at compile time.
+                match Into::<core::convert::Infallible>::into(e) {}
+            }
+        }
+    }
+
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock is poisoned.
+    pub fn try_last_id(&self) -> Result<ID, Error> {
+        #[cfg(feature = "parking-lot")]
+        {
+            Ok(*self.state.lock())
+        }
+        #[cfg(not(feature = "parking-lot"))]
+        {
+            Ok(*self.state.lock()?)
+        }
+    }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
+
     #[cold]
     #[inline(never)]
-    fn cold_clock_behind(now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
+    fn cold_clock_behind(&self, now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
         let yield_for = current_ts - now;
         debug_assert!(yield_for >= ID::ZERO);
+        self.stats.clock_behind(yield_for);
         Poll::Pending { yield_for }
     }
 }
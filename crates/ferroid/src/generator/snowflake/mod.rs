@@ -1,10 +1,14 @@
-#[cfg(all(feature = "atomic", target_has_atomic = "64"))]
+#[cfg(all(feature = "atomic", target_has_atomic = "32"))]
 mod atomic;
 #[cfg(feature = "basic")]
 mod basic;
+#[cfg(feature = "critical-section")]
+mod critical_section;
 mod interface;
 #[cfg(feature = "lock")]
 mod lock;
+#[cfg(feature = "lock")]
+mod multi_lane;
 #[cfg(all(
     test,
     feature = "std",
@@ -17,15 +21,24 @@ mod tests;
 
 #[cfg_attr(
     docsrs,
-    doc(cfg(all(feature = "snowflake", feature = "atomic", target_has_atomic = "64")))
+    doc(cfg(all(feature = "snowflake", feature = "atomic", target_has_atomic = "32")))
 )]
-#[cfg(all(feature = "atomic", target_has_atomic = "64"))]
+#[cfg(all(feature = "atomic", target_has_atomic = "32"))]
 pub use atomic::*;
 #[cfg_attr(docsrs, doc(cfg(all(feature = "snowflake", feature = "basic"))))]
 #[cfg(feature = "basic")]
 pub use basic::*;
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "snowflake", feature = "critical-section")))
+)]
+#[cfg(feature = "critical-section")]
+pub use critical_section::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "snowflake")))]
 pub use interface::*;
 #[cfg_attr(docsrs, doc(cfg(all(feature = "snowflake", feature = "lock"))))]
 #[cfg(feature = "lock")]
 pub use lock::*;
+#[cfg_attr(docsrs, doc(cfg(all(feature = "snowflake", feature = "lock"))))]
+#[cfg(feature = "lock")]
+pub use multi_lane::*;
@@ -0,0 +1,326 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::cmp::Ordering;
+
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+use crate::{
+    generator::{
+        Counters, Error, Mutex, Poll, Result, SnowflakeGenerator, advance_target,
+        timestamp_overflow,
+    },
+    id::SnowflakeId,
+    time::TimeSource,
+};
+
+/// A lock-based Snowflake ID generator that owns several machine IDs
+/// ("lanes") and rotates to the next one when a lane's sequence is exhausted
+/// within a tick.
+///
+/// A single machine ID can issue at most `max_sequence() + 1` IDs per tick
+/// (4096 per millisecond for [`SnowflakeTwitterId`]). With `N` lanes, this
+/// generator issues up to `N` times as many before returning
+/// [`Poll::Pending`], without changing the layout.
+///
+/// ## Ordering
+/// Lanes are kept sorted by machine ID. Each tick starts on the lowest lane and
+/// only moves up, so IDs from one generator are strictly increasing, exactly
+/// as with [`LockSnowflakeGenerator`]. Because IDs order by timestamp before
+/// machine ID, IDs from other nodes with machine IDs between this generator's
+/// lanes interleave with its IDs within a tick; that is no different from
+/// any other pair of nodes sharing an epoch.
+///
+/// Every machine ID handed to this generator must be reserved for it alone,
+/// just like the single machine ID of the other generators.
+///
+/// ## Features
+/// - ✅ Thread-safe
+/// - ✅ Multiplies per-node throughput by the number of lanes
+///
+/// ## Recommended When
+/// - A single machine ID's sequence space saturates under load
+/// - The machine ID space has room to spare
+///
+/// ## See Also
+/// - [`LockSnowflakeGenerator`]
+/// - [`AtomicSnowflakeGenerator`]
+///
+/// [`SnowflakeTwitterId`]: crate::id::SnowflakeTwitterId
+/// [`LockSnowflakeGenerator`]: crate::generator::LockSnowflakeGenerator
+/// [`AtomicSnowflakeGenerator`]: crate::generator::AtomicSnowflakeGenerator
+pub struct MultiLaneSnowflakeGenerator<ID, T>
+where
+    ID: SnowflakeId,
+    T: TimeSource<ID::Ty>,
+{
+    #[cfg(feature = "cache-padded")]
+    state: crossbeam_utils::CachePadded<Mutex<ID>>,
+    #[cfg(not(feature = "cache-padded"))]
+    state: Mutex<ID>,
+    lanes: Box<[ID::Ty]>,
+    time: T,
+    stats: Counters,
+}
+
+impl<ID, T> MultiLaneSnowflakeGenerator<ID, T>
+where
+    ID: SnowflakeId,
+    T: TimeSource<ID::Ty>,
+{
+    /// Creates a new [`MultiLaneSnowflakeGenerator`] owning the given machine
+    /// IDs.
+    ///
+    /// Duplicate machine IDs are ignored, and the order they are given in does
+    /// not matter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `machine_ids` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::MultiLaneSnowflakeGenerator,
+    ///     id::SnowflakeTwitterId,
+    ///     time::{MonotonicClock, TWITTER_EPOCH},
+    /// };
+    ///
+    /// let generator = MultiLaneSnowflakeGenerator::new(
+    ///     [8, 9, 10, 11],
+    ///     MonotonicClock::<1>::with_epoch(TWITTER_EPOCH),
+    /// );
+    ///
+    /// let id: SnowflakeTwitterId = generator.try_next_id(|_| std::thread::yield_now()).unwrap();
+    /// assert_eq!(generator.lanes(), &[8, 9, 10, 11]);
+    /// ```
+    pub fn new(machine_ids: impl IntoIterator<Item = ID::Ty>, time: T) -> Self {
+        let mut lanes: Vec<ID::Ty> = machine_ids.into_iter().collect();
+        lanes.sort_unstable();
+        lanes.dedup();
+        assert!(
+            !lanes.is_empty(),
+            "a multi-lane generator needs at least one machine ID"
+        );
+        let id = ID::from_components(ID::ZERO, lanes[0], ID::ZERO);
+        Self {
+            #[cfg(feature = "cache-padded")]
+            state: crossbeam_utils::CachePadded::new(Mutex::new(id)),
+            #[cfg(not(feature = "cache-padded"))]
+            state: Mutex::new(id),
+            lanes: lanes.into_boxed_slice(),
+            time,
+            stats: Counters::new(),
+        }
+    }
+
+    /// Returns the machine IDs owned by this generator, in ascending order.
+    pub fn lanes(&self) -> &[ID::Ty] {
+        &self.lanes
+    }
+
+    /// Generates a new ID.
+    ///
+    /// Returns a new, time-ordered, unique ID.
+    #[cfg(feature = "parking-lot")]
+    pub fn next_id(&self, f: impl FnMut(ID::Ty)) -> ID {
+        match self.try_next_id(f) {
+            Ok(id) => id,
+            Err(e) =>
+            {
+                #[allow(unreachable_code)]
+                match e {}
+            }
+        }
+    }
+
+    /// Generates a new ID.
+    ///
+    /// Returns a new, time-ordered, unique ID with fallible error handling.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the generator fails, such as from lock poisoning.
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self, f)))]
+    pub fn try_next_id(&self, mut f: impl FnMut(ID::Ty)) -> Result<ID, Error> {
+        loop {
+            match self.try_poll_id()? {
+                Poll::Ready { id } => break Ok(id),
+                Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
+            }
+        }
+    }
+
+    /// Attempts to generate the next available ID.
+    ///
+    /// Returns [`Poll::Pending`] only once every lane's sequence is exhausted
+    /// for the current tick.
+    #[cfg(feature = "parking-lot")]
+    pub fn poll_id(&self) -> Poll<ID>
+    where
+        Error: Into<core::convert::Infallible>,
+    {
+        match self.try_poll_id() {
+            Ok(id) => id,
+            Err(e) => {
+                #[allow(unreachable_code)]
+                // `into()` satisfies the trait bound at compile time.
+                match Into::<core::convert::Infallible>::into(e) {}
+            }
+        }
+    }
+
+    /// Attempts to generate a new Snowflake ID with fallible error handling.
+    ///
+    /// # Returns
+    /// - `Ok(Poll::Ready { id })`: A new ID is available
+    /// - `Ok(Poll::Pending { yield_for })`: Every lane is exhausted for this
+    ///   tick, or the clock moved backwards; wait `yield_for` time-source units
+    /// - `Err(e)`: the lock was poisoned
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the generator fails, such as from lock poisoning.
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self)))]
+    pub fn try_poll_id(&self) -> Result<Poll<ID>, Error> {
+        let now = self.time.current_millis();
+
+        let mut id = {
+            #[cfg(feature = "parking-lot")]
+            {
+                self.state.lock()
+            }
+            #[cfg(not(feature = "parking-lot"))]
+            {
+                self.state.lock()?
+            }
+        };
+
+        let current_ts = id.timestamp();
+        match now.cmp(&current_ts) {
+            Ordering::Equal => {
+                if id.has_sequence_room() {
+                    *id = id.increment_sequence();
+                } else if let Some(&lane) = self.next_lane(id.machine_id()) {
+                    *id = ID::from_components(current_ts, lane, ID::ZERO);
+                } else {
+                    self.stats.exhausted();
+                    return Ok(Poll::Pending { yield_for: ID::ONE });
+                }
+                self.stats.issued();
+                Ok(Poll::Ready { id: *id })
+            }
+            Ordering::Greater if now > ID::max_timestamp() => {
+                Ok(Poll::TimestampOverflow { timestamp: now })
+            }
+            Ordering::Greater => {
+                *id = ID::from_components(now, self.lanes[0], ID::ZERO);
+                self.stats.issued();
+                Ok(Poll::Ready { id: *id })
+            }
+            Ordering::Less => Ok(self.cold_clock_behind(now, current_ts)),
+        }
+    }
+
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`.
+    ///
+    /// Within `id`'s tick, the generator continues on the lowest lane whose
+    /// IDs sort after `id`. See [`BasicSnowflakeGenerator::advance_to`] for
+    /// details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock is poisoned.
+    ///
+    /// [`BasicSnowflakeGenerator::advance_to`]: crate::generator::BasicSnowflakeGenerator::advance_to
+    pub fn try_advance_to(&self, id: ID) -> Result<(), Error> {
+        let mut current = {
+            #[cfg(feature = "parking-lot")]
+            {
+                self.state.lock()
+            }
+            #[cfg(not(feature = "parking-lot"))]
+            {
+                self.state.lock()?
+            }
+        };
+        let observed = id.machine_id();
+        let lane = self
+            .lanes
+            .iter()
+            .find(|&&lane| lane >= observed)
+            .unwrap_or(&self.lanes[self.lanes.len() - 1]);
+        let target = advance_target(id, *lane);
+        if target > *current {
+            *current = target;
+        }
+        Ok(())
+    }
+
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock is poisoned.
+    pub fn try_last_id(&self) -> Result<ID, Error> {
+        #[cfg(feature = "parking-lot")]
+        {
+            Ok(*self.state.lock())
+        }
+        #[cfg(not(feature = "parking-lot"))]
+        {
+            Ok(*self.state.lock()?)
+        }
+    }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
+
+    /// Returns the lane after `machine_id`, if any.
+    fn next_lane(&self, machine_id: ID::Ty) -> Option<&ID::Ty> {
+        let index = self.lanes.binary_search(&machine_id).ok()?;
+        self.lanes.get(index + 1)
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn cold_clock_behind(&self, now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
+        let yield_for = current_ts - now;
+        debug_assert!(yield_for >= ID::ZERO);
+        self.stats.clock_behind(yield_for);
+        Poll::Pending { yield_for }
+    }
+}
+
+impl<ID, T> SnowflakeGenerator<ID, T> for MultiLaneSnowflakeGenerator<ID, T>
+where
+    ID: SnowflakeId,
+    T: TimeSource<ID::Ty>,
+{
+    type Err = Error;
+
+    /// Creates a single-lane generator; use
+    /// [`MultiLaneSnowflakeGenerator::new`] directly to own several machine
+    /// IDs.
+    fn new(machine_id: ID::Ty, time: T) -> Self {
+        Self::new([machine_id], time)
+    }
+
+    fn try_next_id(&self, f: impl FnMut(ID::Ty)) -> Result<ID, Self::Err> {
+        self.try_next_id(f)
+    }
+
+    fn try_poll_id(&self) -> Result<Poll<ID>, Self::Err> {
+        self.try_poll_id()
+    }
+}
@@ -1,5 +1,5 @@
 use alloc::{rc::Rc, sync::Arc, vec, vec::Vec};
-use core::cell::Cell;
+use core::{cell::Cell, time::Duration};
 use std::{
     collections::HashSet,
     panic,
@@ -8,9 +8,12 @@ use std::{
 };
 
 use crate::{
-    generator::{BasicSnowflakeGenerator, LockSnowflakeGenerator, Poll, SnowflakeGenerator},
+    generator::{
+        BasicSnowflakeGenerator, LockSnowflakeGenerator, MultiLaneSnowflakeGenerator, Poll,
+        SnowflakeGenerator,
+    },
     id::{Id, SnowflakeId, SnowflakeTwitterId, ToU64},
-    time::{MonotonicClock, TimeSource, UNIX_EPOCH},
+    time::{MonotonicClock, TWITTER_EPOCH, TimeSource, UNIX_EPOCH},
 };
 
 struct MockTime {
@@ -44,6 +47,12 @@ struct MockStepTime {
     index: Cell<usize>,
 }
 
+impl TimeSource<u32> for MockTime {
+    fn current_millis(&self) -> u32 {
+        u32::try_from(self.millis).unwrap()
+    }
+}
+
 struct FixedTime;
 impl TimeSource<u64> for FixedTime {
     fn current_millis(&self) -> u64 {
@@ -51,12 +60,53 @@ impl TimeSource<u64> for FixedTime {
     }
 }
 
+impl TimeSource<u32> for FixedTime {
+    fn current_millis(&self) -> u32 {
+        0
+    }
+}
+
+impl TimeSource<u128> for FixedTime {
+    fn current_millis(&self) -> u128 {
+        0
+    }
+}
+
+// Local `define_snowflake_id!` expansions are linted as if hand-written.
+#[allow(clippy::wrong_self_convention)]
+mod layouts {
+    use crate::define_snowflake_id;
+
+    define_snowflake_id!(
+        /// A 32-bit layout for embedded targets.
+        Snowflake32, u32,
+        reserved: 0,
+        timestamp: 20,
+        machine_id: 4,
+        sequence: 8
+    );
+
+    #[cfg(any(target_has_atomic = "128", feature = "critical-section"))]
+    define_snowflake_id!(
+        /// A 128-bit layout with a wide machine ID field.
+        Snowflake128, u128,
+        reserved: 0,
+        timestamp: 48,
+        machine_id: 64,
+        sequence: 16
+    );
+}
+use layouts::Snowflake32;
+#[cfg(any(target_has_atomic = "128", feature = "critical-section"))]
+use layouts::Snowflake128;
+
 trait IdGenStatusExt<T>
 where
     T: Id,
 {
     fn unwrap_ready(self) -> T;
     fn unwrap_pending(self) -> T::Ty;
+    fn unwrap_overflow(self) -> T::Ty;
 }
 
 impl<T> IdGenStatusExt<T> for Poll<T>
@@ -69,6 +119,9 @@ where
             Self::Pending { yield_for } => {
                 panic!("unexpected pending (yield for: {yield_for})")
             }
+            Self::TimestampOverflow { timestamp } => {
+                panic!("unexpected overflow (timestamp: {timestamp})")
+            }
         }
     }
 
@@ -76,6 +129,19 @@ where
         match self {
             Self::Ready { id } => panic!("unexpected ready ({id:?})"),
             Self::Pending { yield_for } => yield_for,
+            Self::TimestampOverflow { timestamp } => {
+                panic!("unexpected overflow (timestamp: {timestamp})")
+            }
+        }
+    }
+
+    fn unwrap_overflow(self) -> T::Ty {
+        match self {
+            Self::Ready { id } => panic!("unexpected ready ({id:?})"),
+            Self::Pending { yield_for } => {
+                panic!("unexpected pending (yield for: {yield_for})")
+            }
+            Self::TimestampOverflow { timestamp } => timestamp,
         }
     }
 }
@@ -109,6 +175,18 @@ where
     assert_eq!(yield_for, ID::ONE);
 }
 
+fn run_generator_reports_timestamp_overflow<G, ID, T>(generator: &G)
+where
+    G: SnowflakeGenerator<ID, T>,
+    ID: SnowflakeId,
+    T: TimeSource<ID::Ty>,
+{
+    let timestamp = generator.try_poll_id().unwrap().unwrap_overflow();
+    assert_eq!(timestamp, ID::max_timestamp() + ID::ONE);
+    // The state is untouched, so the generator keeps reporting the overflow.
+    generator.try_poll_id().unwrap().unwrap_overflow();
+}
+
 fn run_generator_handles_rollover<G, ID, T>(generator: &G, shared_time: &SharedMockStepTime)
 where
     G: SnowflakeGenerator<ID, T>,
@@ -162,6 +240,9 @@ where
                 Poll::Pending { .. } => {
                     core::hint::spin_loop();
                 }
+                Poll::TimestampOverflow { timestamp } => {
+                    panic!("unexpected overflow ({timestamp})")
+                }
             }
         }
     }
@@ -194,6 +275,9 @@ where
                                 break;
                             }
                             Poll::Pending { .. } => std::thread::yield_now(),
+                            Poll::TimestampOverflow { timestamp } => {
+                                panic!("unexpected overflow ({timestamp})")
+                            }
                         }
                     }
                 }
@@ -232,6 +316,17 @@ fn atomic_generator_sequence_test() {
     run_id_sequence_increments_within_same_tick(&generator);
 }
 
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_sequence_test() {
+    use crate::generator::CriticalSectionSnowflakeGenerator;
+
+    let mock_time = MockTime { millis: 42 };
+    let generator: CriticalSectionSnowflakeGenerator<SnowflakeTwitterId, _> =
+        CriticalSectionSnowflakeGenerator::new(0, mock_time);
+    run_id_sequence_increments_within_same_tick(&generator);
+}
+
 #[test]
 fn basic_generator_pending_test() {
     let generator: BasicSnowflakeGenerator<SnowflakeTwitterId, _> =
@@ -271,6 +366,312 @@ fn atomic_generator_pending_test() {
     run_generator_returns_pending_when_sequence_exhausted(&generator);
 }
 
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_pending_test() {
+    use crate::generator::CriticalSectionSnowflakeGenerator;
+
+    let generator: CriticalSectionSnowflakeGenerator<SnowflakeTwitterId, _> =
+        CriticalSectionSnowflakeGenerator::from_components(
+            0,
+            0,
+            SnowflakeTwitterId::max_sequence(),
+            FixedTime,
+        );
+    run_generator_returns_pending_when_sequence_exhausted(&generator);
+}
+
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_u128_sequence_test() {
+    use crate::generator::CriticalSectionSnowflakeGenerator;
+
+    let mock_time = MockTime { millis: 42 };
+    let generator: CriticalSectionSnowflakeGenerator<Snowflake128, _> =
+        CriticalSectionSnowflakeGenerator::new(0, mock_time);
+    run_id_sequence_increments_within_same_tick(&generator);
+}
+
+#[test]
+#[cfg(target_has_atomic = "32")]
+fn atomic_generator_u32_sequence_test() {
+    use crate::generator::AtomicSnowflakeGenerator;
+
+    let mock_time = MockTime { millis: 42 };
+    let generator: AtomicSnowflakeGenerator<Snowflake32, _> =
+        AtomicSnowflakeGenerator::new(Snowflake32::max_machine_id(), mock_time);
+    run_id_sequence_increments_within_same_tick(&generator);
+}
+
+#[test]
+#[cfg(target_has_atomic = "32")]
+fn atomic_generator_u32_pending_test() {
+    use crate::generator::AtomicSnowflakeGenerator;
+
+    let generator: AtomicSnowflakeGenerator<Snowflake32, _> =
+        AtomicSnowflakeGenerator::from_components(0, 0, Snowflake32::max_sequence(), FixedTime);
+    run_generator_returns_pending_when_sequence_exhausted(&generator);
+}
+
+#[test]
+#[cfg(target_has_atomic = "128")]
+fn atomic_generator_u128_sequence_test() {
+    use crate::generator::AtomicSnowflakeGenerator;
+
+    let mock_time = MockTime { millis: 42 };
+    let generator: AtomicSnowflakeGenerator<Snowflake128, _> =
+        AtomicSnowflakeGenerator::new(Snowflake128::max_machine_id(), mock_time);
+    run_id_sequence_increments_within_same_tick(&generator);
+    let id = generator.poll_id().unwrap_ready();
+    assert_eq!(id.machine_id(), Snowflake128::max_machine_id());
+}
+
+#[test]
+#[cfg(target_has_atomic = "128")]
+fn atomic_generator_u128_pending_test() {
+    use crate::generator::AtomicSnowflakeGenerator;
+
+    let generator: AtomicSnowflakeGenerator<Snowflake128, _> =
+        AtomicSnowflakeGenerator::from_components(0, 0, Snowflake128::max_sequence(), FixedTime);
+    run_generator_returns_pending_when_sequence_exhausted(&generator);
+}
+
+#[test]
+fn basic_generator_timestamp_overflow_test() {
+    let mock_time = MockTime {
+        millis: SnowflakeTwitterId::max_timestamp() + 1,
+    };
+    let generator: BasicSnowflakeGenerator<SnowflakeTwitterId, _> =
+        BasicSnowflakeGenerator::from_components(
+            SnowflakeTwitterId::max_timestamp(),
+            0,
+            0,
+            mock_time,
+        );
+    run_generator_reports_timestamp_overflow(&generator);
+}
+
+#[test]
+fn lock_generator_timestamp_overflow_test() {
+    let mock_time = MockTime {
+        millis: SnowflakeTwitterId::max_timestamp() + 1,
+    };
+    let generator: LockSnowflakeGenerator<SnowflakeTwitterId, _> =
+        LockSnowflakeGenerator::new(0, mock_time);
+    run_generator_reports_timestamp_overflow(&generator);
+}
+
+#[test]
+#[cfg(target_has_atomic = "64")]
+fn atomic_generator_timestamp_overflow_test() {
+    use crate::generator::AtomicSnowflakeGenerator;
+
+    let mock_time = MockTime {
+        millis: SnowflakeTwitterId::max_timestamp() + 1,
+    };
+    let generator: AtomicSnowflakeGenerator<SnowflakeTwitterId, _> =
+        AtomicSnowflakeGenerator::new(0, mock_time);
+    run_generator_reports_timestamp_overflow(&generator);
+}
+
+#[test]
+#[should_panic(expected = "exceeds the ID layout's maximum timestamp")]
+fn basic_generator_next_id_panics_on_timestamp_overflow() {
+    let mock_time = MockTime {
+        millis: SnowflakeTwitterId::max_timestamp() + 1,
+    };
+    let generator: BasicSnowflakeGenerator<SnowflakeTwitterId, _> =
+        BasicSnowflakeGenerator::new(0, mock_time);
+    generator.next_id(|_| {});
+}
+
+#[test]
+fn remaining_lifetime_counts_down_to_overflow() {
+    let max = SnowflakeTwitterId::max_timestamp();
+    let at = |millis| SnowflakeTwitterId::remaining_lifetime(&MockTime { millis });
+
+    assert_eq!(at(0), Duration::from_millis(max + 1));
+    assert_eq!(at(max - 999), Duration::from_secs(1));
+    assert_eq!(at(max), Duration::from_millis(1));
+    assert_eq!(at(max + 1), Duration::ZERO);
+}
+
+#[test]
+fn exhaustion_date_for_twitter_layout() {
+    let date = SnowflakeTwitterId::exhaustion_date::<MockTime>(TWITTER_EPOCH);
+    assert_eq!(date, TWITTER_EPOCH + Duration::from_millis(1 << 41));
+    // 2080-07-10T17:30:30.209Z
+    assert_eq!(date.as_millis(), 3_487_858_230_209);
+}
+
+#[test]
+fn basic_generator_advance_to_orders_after_observed_id() {
+    let observed =
+        |machine_id, sequence| SnowflakeTwitterId::from_components(100, machine_id, sequence);
+
+    // Same machine: continue the observed sequence.
+    let generator: BasicSnowflakeGenerator<SnowflakeTwitterId, _> =
+        BasicSnowflakeGenerator::new(5, MockTime { millis: 100 });
+    generator.advance_to(observed(5, 10));
+    assert_eq!(generator.last_id(), observed(5, 10));
+    assert_eq!(generator.poll_id().unwrap_ready(), observed(5, 11));
+
+    // Older IDs never move the state backwards.
+    generator.advance_to(SnowflakeTwitterId::from_components(50, 5, 4000));
+    assert_eq!(generator.last_id(), observed(5, 11));
+
+    // Lower machine: any sequence in the same tick sorts after it.
+    let generator: BasicSnowflakeGenerator<SnowflakeTwitterId, _> =
+        BasicSnowflakeGenerator::new(5, MockTime { millis: 100 });
+    generator.advance_to(observed(3, 4000));
+    let id = generator.poll_id().unwrap_ready();
+    assert!(id > observed(3, 4000));
+    assert_eq!(id.machine_id(), 5);
+
+    // Higher machine: wait for the next tick.
+    let generator: BasicSnowflakeGenerator<SnowflakeTwitterId, _> =
+        BasicSnowflakeGenerator::new(5, MockTime { millis: 100 });
+    generator.advance_to(observed(7, 0));
+    assert_eq!(generator.poll_id().unwrap_pending(), 1);
+}
+
+#[test]
+fn lock_generator_advance_to_orders_after_observed_id() {
+    let generator: LockSnowflakeGenerator<SnowflakeTwitterId, _> =
+        LockSnowflakeGenerator::new(5, MockTime { millis: 100 });
+    let observed = SnowflakeTwitterId::from_components(100, 5, 10);
+    generator.try_advance_to(observed).unwrap();
+    assert_eq!(generator.try_last_id().unwrap(), observed);
+    assert!(generator.try_poll_id().unwrap().unwrap_ready() > observed);
+
+    generator
+        .try_advance_to(SnowflakeTwitterId::from_components(100, 7, 0))
+        .unwrap();
+    assert_eq!(generator.try_poll_id().unwrap().unwrap_pending(), 1);
+}
+
+#[test]
+#[cfg(target_has_atomic = "64")]
+fn atomic_generator_advance_to_orders_after_observed_id() {
+    use crate::generator::AtomicSnowflakeGenerator;
+
+    let generator: AtomicSnowflakeGenerator<SnowflakeTwitterId, _> =
+        AtomicSnowflakeGenerator::new(5, MockTime { millis: 100 });
+    let observed = SnowflakeTwitterId::from_components(100, 5, 10);
+    generator.advance_to(observed);
+    assert_eq!(generator.last_id(), observed);
+    assert!(generator.poll_id().unwrap_ready() > observed);
+
+    generator.advance_to(SnowflakeTwitterId::from_components(99, 9, 0));
+    assert_eq!(
+        generator.last_id(),
+        SnowflakeTwitterId::from_components(100, 5, 11)
+    );
+
+    generator.advance_to(SnowflakeTwitterId::from_components(100, 7, 0));
+    assert_eq!(generator.poll_id().unwrap_pending(), 1);
+}
+
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_advance_to_orders_after_observed_id() {
+    use crate::generator::CriticalSectionSnowflakeGenerator;
+
+    let generator: CriticalSectionSnowflakeGenerator<SnowflakeTwitterId, _> =
+        CriticalSectionSnowflakeGenerator::new(5, MockTime { millis: 100 });
+    let observed = SnowflakeTwitterId::from_components(100, 5, 10);
+    generator.advance_to(observed);
+    assert_eq!(generator.last_id(), observed);
+    assert!(generator.poll_id().unwrap_ready() > observed);
+
+    generator.advance_to(SnowflakeTwitterId::from_components(100, 7, 0));
+    assert_eq!(generator.poll_id().unwrap_pending(), 1);
+}
+
+#[test]
+fn multi_lane_generator_sequence_test() {
+    let mock_time = MockTime { millis: 42 };
+    let generator: MultiLaneSnowflakeGenerator<SnowflakeTwitterId, _> =
+        SnowflakeGenerator::new(0, mock_time);
+    run_id_sequence_increments_within_same_tick(&generator);
+}
+
+#[test]
+fn multi_lane_generator_rotates_lanes_within_tick() {
+    let generator: MultiLaneSnowflakeGenerator<SnowflakeTwitterId, _> =
+        MultiLaneSnowflakeGenerator::new([9, 3, 5, 3], MockTime { millis: 1 });
+    assert_eq!(generator.lanes(), &[3, 5, 9]);
+
+    let per_lane = usize::try_from(SnowflakeTwitterId::max_sequence() + 1).unwrap();
+    let mut last = None;
+    for i in 0..3 * per_lane {
+        let id = generator.try_poll_id().unwrap().unwrap_ready();
+        assert_eq!(id.machine_id(), [3, 5, 9][i / per_lane]);
+        assert!(last < Some(id));
+        last = Some(id);
+    }
+    assert_eq!(generator.try_poll_id().unwrap().unwrap_pending(), 1);
+
+    #[cfg(feature = "stats")]
+    {
+        let stats = generator.stats();
+        assert_eq!(stats.ids_issued, 3 * per_lane as u64);
+        assert_eq!(stats.pending_exhausted, 1);
+    }
+}
+
+#[test]
+fn multi_lane_generator_restarts_on_lowest_lane_each_tick() {
+    let shared_time = SharedMockStepTime {
+        clock: Rc::new(MockStepTime {
+            values: vec![0, 1],
+            index: Cell::new(0),
+        }),
+    };
+    let generator: MultiLaneSnowflakeGenerator<SnowflakeTwitterId, _> =
+        MultiLaneSnowflakeGenerator::new([4, 6], shared_time.clone());
+    generator
+        .try_advance_to(SnowflakeTwitterId::from_components(
+            0,
+            4,
+            SnowflakeTwitterId::max_sequence(),
+        ))
+        .unwrap();
+    assert_eq!(
+        generator.try_poll_id().unwrap().unwrap_ready(),
+        SnowflakeTwitterId::from_components(0, 6, 0)
+    );
+
+    shared_time.clock.index.set(1);
+    assert_eq!(
+        generator.try_poll_id().unwrap().unwrap_ready(),
+        SnowflakeTwitterId::from_components(1, 4, 0)
+    );
+}
+
+#[test]
+fn multi_lane_generator_advance_to_picks_lane_after_observed_id() {
+    let generator: MultiLaneSnowflakeGenerator<SnowflakeTwitterId, _> =
+        MultiLaneSnowflakeGenerator::new([2, 6], FixedTime);
+    let observed = SnowflakeTwitterId::from_components(0, 4, 100);
+    generator.try_advance_to(observed).unwrap();
+    let id = generator.try_poll_id().unwrap().unwrap_ready();
+    assert!(id > observed);
+    assert_eq!(id.machine_id(), 6);
+
+    // Past the highest lane: wait for the next tick.
+    generator
+        .try_advance_to(SnowflakeTwitterId::from_components(0, 7, 0))
+        .unwrap();
+    assert_eq!(generator.try_poll_id().unwrap().unwrap_pending(), 1);
+}
+
+#[test]
+#[should_panic(expected = "at least one machine ID")]
+fn multi_lane_generator_requires_a_lane() {
+    let _ = MultiLaneSnowflakeGenerator::<SnowflakeTwitterId, _>::new([], FixedTime);
+}
+
 #[test]
 fn basic_generator_rollover_test() {
     let shared_time = SharedMockStepTime {
@@ -313,6 +714,22 @@ fn atomic_generator_rollover_test() {
     run_generator_handles_rollover(&generator, &shared_time);
 }
 
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_rollover_test() {
+    use crate::generator::CriticalSectionSnowflakeGenerator;
+
+    let shared_time = SharedMockStepTime {
+        clock: Rc::new(MockStepTime {
+            values: vec![42, 43],
+            index: Cell::new(0),
+        }),
+    };
+    let generator: CriticalSectionSnowflakeGenerator<SnowflakeTwitterId, _> =
+        CriticalSectionSnowflakeGenerator::new(1, shared_time.clone());
+    run_generator_handles_rollover(&generator, &shared_time);
+}
+
 #[test]
 fn basic_generator_monotonic_clock_sequence_increments() {
     let clock = MonotonicClock::default();
@@ -356,6 +773,14 @@ fn lock_generator_threaded_monotonic() {
     });
 }
 
+#[test]
+fn multi_lane_generator_threaded_monotonic() {
+    let clock = MonotonicClock::default();
+    run_generator_monotonic_threaded(move || {
+        MultiLaneSnowflakeGenerator::<SnowflakeTwitterId, _>::new([0, 1, 2], clock.clone())
+    });
+}
+
 #[test]
 #[cfg(target_has_atomic = "64")]
 fn atomic_generator_threaded_monotonic() {
@@ -367,6 +792,77 @@ fn atomic_generator_threaded_monotonic() {
     });
 }
 
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_threaded_monotonic() {
+    use crate::generator::CriticalSectionSnowflakeGenerator;
+
+    let clock = MonotonicClock::default();
+    run_generator_monotonic_threaded(move || {
+        CriticalSectionSnowflakeGenerator::<SnowflakeTwitterId, _>::new(0, clock.clone())
+    });
+}
+
+#[test]
+#[cfg(target_has_atomic = "64")]
+fn atomic_generator_threaded_exhaustion_issues_each_sequence_once() {
+    use crate::generator::AtomicSnowflakeGenerator;
+
+    struct SettableTime(Arc<core::sync::atomic::AtomicU64>);
+    impl TimeSource<u64> for SettableTime {
+        fn current_millis(&self) -> u64 {
+            self.0.load(core::sync::atomic::Ordering::Relaxed)
+        }
+    }
+
+    const THREADS: usize = 8;
+    const POLLS_PER_THREAD: usize = 1024;
+
+    let now = Arc::new(core::sync::atomic::AtomicU64::new(1));
+
+    let generator: AtomicSnowflakeGenerator<SnowflakeTwitterId, _> =
+        AtomicSnowflakeGenerator::new(3, SettableTime(Arc::clone(&now)));
+    let seen = Mutex::new(HashSet::new());
+
+    scope(|s| {
+        for _ in 0..THREADS {
+            s.spawn(|| {
+                let mut ids = Vec::new();
+                for _ in 0..POLLS_PER_THREAD {
+                    match generator.poll_id() {
+                        Poll::Ready { id } => ids.push(id),
+                        Poll::Pending { .. } => {}
+                        Poll::TimestampOverflow { .. } => unreachable!(),
+                    }
+                }
+                let mut seen = seen.lock().unwrap();
+                for id in ids {
+                    assert_eq!(id.machine_id(), 3);
+                    assert!(seen.insert(id), "duplicate ID {id}");
+                }
+            });
+        }
+    });
+
+    let seen = seen.into_inner().unwrap();
+    assert_eq!(
+        seen.len() as u64,
+        SnowflakeTwitterId::max_sequence() + 1,
+        "every sequence of the tick is issued exactly once"
+    );
+    assert_eq!(
+        generator.last_id(),
+        SnowflakeTwitterId::from_components(1, 3, SnowflakeTwitterId::max_sequence())
+    );
+
+    // Reservations that overshot the exhausted tick must not block rollover.
+    now.store(2, core::sync::atomic::Ordering::Relaxed);
+    let Poll::Ready { id } = generator.poll_id() else {
+        panic!("expected rollover to the next tick");
+    };
+    assert_eq!(id, SnowflakeTwitterId::from_components(2, 3, 0));
+}
+
 #[cfg(not(feature = "parking-lot"))]
 #[test]
 fn lock_is_poisoned_on_panic_std_mutex() {
@@ -437,3 +933,45 @@ fn lock_can_call_next_id() {
     let _id: SnowflakeTwitterId = generator.next_id(backoff);
     let _id: SnowflakeTwitterId = SnowflakeGenerator::next_id(&generator, backoff);
 }
+
+#[cfg(feature = "stats")]
+#[test]
+fn basic_generator_stats_count_each_outcome() {
+    let shared_time = SharedMockStepTime {
+        clock: Rc::new(MockStepTime {
+            values: vec![10, 0],
+            index: Cell::new(0),
+        }),
+    };
+    let generator: BasicSnowflakeGenerator<SnowflakeTwitterId, _> =
+        BasicSnowflakeGenerator::from_components(10, 0, 0, shared_time.clone());
+
+    generator.poll_id().unwrap_ready();
+    shared_time.clock.index.set(1);
+    assert_eq!(generator.poll_id().unwrap_pending(), 10);
+
+    let stats = generator.stats();
+    assert_eq!(stats.ids_issued, 1);
+    assert_eq!(stats.pending_clock_behind, 1);
+    assert_eq!(stats.max_regression, 10);
+    assert_eq!(stats.pending(), 1);
+}
+
+#[cfg(feature = "stats")]
+#[test]
+fn lock_generator_stats_count_exhaustion() {
+    let generator: LockSnowflakeGenerator<SnowflakeTwitterId, _> =
+        LockSnowflakeGenerator::from_components(
+            0,
+            0,
+            SnowflakeTwitterId::max_sequence(),
+            FixedTime,
+        );
+    generator.try_poll_id().unwrap().unwrap_pending();
+    generator.try_poll_id().unwrap().unwrap_pending();
+
+    let stats = generator.stats();
+    assert_eq!(stats.ids_issued, 0);
+    assert_eq!(stats.pending_exhausted, 2);
+    assert_eq!(stats.pending_clock_behind, 0);
+}
@@ -0,0 +1,212 @@
+#[cfg(all(feature = "stats", target_has_atomic = "64"))]
+use portable_atomic::AtomicU64 as AtomicCounter;
+#[cfg(all(feature = "stats", not(target_has_atomic = "64")))]
+use portable_atomic::AtomicUsize as AtomicCounter;
+#[cfg(feature = "stats")]
+use portable_atomic::Ordering;
+
+/// A point-in-time snapshot of a generator's counters.
+///
+/// Counters start at zero when the generator is created and are updated with
+/// relaxed atomic operations, so a snapshot taken while other threads are
+/// generating IDs may be slightly stale but never torn per field. Each
+/// generated ID or [`Poll::Pending`] costs a single uncontended atomic
+/// increment, which is cheap enough to leave enabled in production (unlike the
+/// spans added by the `tracing` feature).
+///
+/// On targets without 64-bit atomics, counters are pointer-sized and wrap on
+/// overflow.
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     generator::{BasicSnowflakeGenerator, Poll},
+///     id::SnowflakeTwitterId,
+///     time::{MonotonicClock, TWITTER_EPOCH},
+/// };
+///
+/// let generator = BasicSnowflakeGenerator::<SnowflakeTwitterId, _>::new(
+///     0,
+///     MonotonicClock::<1>::with_epoch(TWITTER_EPOCH),
+/// );
+/// let _ = generator.poll_id();
+///
+/// let stats = generator.stats();
+/// assert_eq!(stats.ids_issued + stats.pending(), 1);
+/// ```
+///
+/// [`Poll::Pending`]: crate::generator::Poll::Pending
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+#[cfg(feature = "stats")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct GeneratorStats {
+    /// The number of IDs returned as [`Poll::Ready`].
+    ///
+    /// [`Poll::Ready`]: crate::generator::Poll::Ready
+    pub ids_issued: u64,
+    /// The number of [`Poll::Pending`] results caused by an exhausted sequence
+    /// (Snowflake) or random component (ULID) within a single tick.
+    ///
+    /// [`Poll::Pending`]: crate::generator::Poll::Pending
+    pub pending_exhausted: u64,
+    /// The number of [`Poll::Pending`] results caused by the clock reporting a
+    /// time before the last issued ID.
+    ///
+    /// [`Poll::Pending`]: crate::generator::Poll::Pending
+    pub pending_clock_behind: u64,
+    /// The number of [`Poll::Pending`] results caused by losing a race to
+    /// another thread (e.g. a failed compare-and-swap).
+    ///
+    /// [`Poll::Pending`]: crate::generator::Poll::Pending
+    pub pending_contention: u64,
+    /// The largest clock regression observed, in time-source units.
+    pub max_regression: u64,
+}
+
+#[cfg(feature = "stats")]
+impl GeneratorStats {
+    /// Returns the total number of [`Poll::Pending`] results, across all
+    /// causes.
+    ///
+    /// [`Poll::Pending`]: crate::generator::Poll::Pending
+    #[must_use]
+    pub const fn pending(&self) -> u64 {
+        self.pending_exhausted
+            .saturating_add(self.pending_clock_behind)
+            .saturating_add(self.pending_contention)
+    }
+
+    /// Publishes this snapshot through the [`metrics`] facade.
+    ///
+    /// Counters are emitted as absolute values, so this can be called
+    /// periodically with fresh snapshots from the same generator. Every series
+    /// carries a `generator` label set to `generator`:
+    ///
+    /// - `ferroid_ids_issued_total` (counter)
+    /// - `ferroid_pending_total` (counter, with a `cause` label of
+    ///   `exhausted`, `clock_behind`, or `contention`)
+    /// - `ferroid_max_regression` (gauge, in time-source units)
+    ///
+    /// [`metrics`]: https://docs.rs/metrics
+    #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+    #[cfg(feature = "metrics")]
+    pub fn record_metrics(&self, generator: &'static str) {
+        metrics::counter!("ferroid_ids_issued_total", "generator" => generator)
+            .absolute(self.ids_issued);
+        for (cause, value) in [
+            ("exhausted", self.pending_exhausted),
+            ("clock_behind", self.pending_clock_behind),
+            ("contention", self.pending_contention),
+        ] {
+            metrics::counter!("ferroid_pending_total", "generator" => generator, "cause" => cause)
+                .absolute(value);
+        }
+        #[allow(clippy::cast_precision_loss)]
+        metrics::gauge!("ferroid_max_regression", "generator" => generator)
+            .set(self.max_regression as f64);
+    }
+}
+
+/// The counters embedded in every generator.
+///
+/// Without the `stats` feature this is a zero-sized type and every method
+/// compiles to nothing.
+pub(crate) struct Counters {
+    #[cfg(feature = "stats")]
+    ids_issued: AtomicCounter,
+    #[cfg(feature = "stats")]
+    pending_exhausted: AtomicCounter,
+    #[cfg(feature = "stats")]
+    pending_clock_behind: AtomicCounter,
+    #[cfg(feature = "stats")]
+    pending_contention: AtomicCounter,
+    #[cfg(feature = "stats")]
+    max_regression: AtomicCounter,
+}
+
+impl Counters {
+    pub(crate) const fn new() -> Self {
+        Self {
+            #[cfg(feature = "stats")]
+            ids_issued: AtomicCounter::new(0),
+            #[cfg(feature = "stats")]
+            pending_exhausted: AtomicCounter::new(0),
+            #[cfg(feature = "stats")]
+            pending_clock_behind: AtomicCounter::new(0),
+            #[cfg(feature = "stats")]
+            pending_contention: AtomicCounter::new(0),
+            #[cfg(feature = "stats")]
+            max_regression: AtomicCounter::new(0),
+        }
+    }
+
+    /// Records an issued ID.
+    #[inline(always)]
+    pub(crate) fn issued(&self) {
+        #[cfg(feature = "stats")]
+        self.ids_issued.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a pending result caused by an exhausted sequence or random
+    /// component.
+    #[inline(always)]
+    pub(crate) fn exhausted(&self) {
+        #[cfg(feature = "stats")]
+        self.pending_exhausted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a pending result caused by losing a race to another thread.
+    #[cfg_attr(not(feature = "atomic"), allow(dead_code))]
+    #[inline(always)]
+    pub(crate) fn contention(&self) {
+        #[cfg(feature = "stats")]
+        self.pending_contention.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a pending result caused by the clock running `regression`
+    /// time-source units behind the generator's state.
+    #[inline(always)]
+    pub(crate) fn clock_behind(&self, regression: impl crate::id::ToU64) {
+        #[cfg(feature = "stats")]
+        {
+            self.pending_clock_behind.fetch_add(1, Ordering::Relaxed);
+            self.max_regression
+                .fetch_max(to_counter(regression.to_u64()), Ordering::Relaxed);
+        }
+        #[cfg(not(feature = "stats"))]
+        let _ = regression;
+    }
+
+    /// Takes a snapshot of the counters.
+    #[cfg(feature = "stats")]
+    pub(crate) fn snapshot(&self) -> GeneratorStats {
+        GeneratorStats {
+            ids_issued: from_counter(self.ids_issued.load(Ordering::Relaxed)),
+            pending_exhausted: from_counter(self.pending_exhausted.load(Ordering::Relaxed)),
+            pending_clock_behind: from_counter(self.pending_clock_behind.load(Ordering::Relaxed)),
+            pending_contention: from_counter(self.pending_contention.load(Ordering::Relaxed)),
+            max_regression: from_counter(self.max_regression.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+#[cfg(all(feature = "stats", target_has_atomic = "64"))]
+const fn to_counter(value: u64) -> u64 {
+    value
+}
+
+#[cfg(all(feature = "stats", target_has_atomic = "64"))]
+const fn from_counter(value: u64) -> u64 {
+    value
+}
+
+#[cfg(all(feature = "stats", not(target_has_atomic = "64")))]
+fn to_counter(value: u64) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
+
+#[cfg(all(feature = "stats", not(target_has_atomic = "64")))]
+fn from_counter(value: usize) -> u64 {
+    value as u64
+}
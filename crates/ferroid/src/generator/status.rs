@@ -9,6 +9,8 @@ use crate::id::Id;
 /// - [`Poll::Ready`] indicates a new ID was successfully generated.
 /// - [`Poll::Pending`] means the generator is throttled and cannot produce a
 ///   new ID until the time source advances past `yield_for`.
+/// - [`Poll::TimestampOverflow`] means the time source has moved past the
+///   largest timestamp the ID layout can represent. This is permanent.
 ///
 /// This allows non-blocking generation loops and clean backoff strategies.
 ///
@@ -35,6 +37,7 @@ use crate::id::Id;
 /// match generator.poll_id() {
 ///     Poll::Ready { id } => println!("ID: {}", id.timestamp()),
 ///     Poll::Pending { yield_for } => println!("Back off for: {yield_for}"),
+///     Poll::TimestampOverflow { timestamp } => panic!("Clock overflowed at: {timestamp}"),
 /// }
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -52,4 +55,29 @@ pub enum Poll<T: Id> {
         /// Time-source units to wait before the next attempt.
         yield_for: T::Ty,
     },
+    /// The time source reported a timestamp that no longer fits in the ID
+    /// layout's timestamp field.
+    ///
+    /// Without this check the timestamp would be masked and IDs would silently
+    /// wrap around to small values. Retrying will not help: the epoch or the
+    /// layout must change. Blocking helpers such as `next_id` panic when they
+    /// encounter this. Use `remaining_lifetime` on [`SnowflakeId`] or
+    /// [`UlidId`] to alert well before it happens.
+    ///
+    /// [`SnowflakeId`]: crate::id::SnowflakeId
+    /// [`UlidId`]: crate::id::UlidId
+    TimestampOverflow {
+        /// The out-of-range timestamp reported by the time source.
+        timestamp: T::Ty,
+    },
+}
+
+/// Panics on behalf of a blocking helper that received
+/// [`Poll::TimestampOverflow`].
+#[cfg(any(feature = "snowflake", feature = "ulid"))]
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn timestamp_overflow(timestamp: impl core::fmt::Display) -> ! {
+    panic!("time source timestamp {timestamp} exceeds the ID layout's maximum timestamp")
 }
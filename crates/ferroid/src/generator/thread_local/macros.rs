@@ -0,0 +1,203 @@
+/// A macro for defining a thread-local ULID generator for any [`UlidId`]
+/// layout, clock, and random source.
+///
+/// The generated type offers the same convenience API as [`Ulid`], backed by a
+/// process-global clock and per-thread [`BasicUlidGenerator`] and
+/// [`BasicMonoUlidGenerator`] instances:
+///
+/// - `new_id()`: a non-monotonic ID (fresh randomness on every call)
+/// - `new_id_mono(f)`: a monotonic ID (increments within the same tick,
+///   calling `f` if the random component saturates)
+/// - `from_timestamp(timestamp)`: an ID for a timestamp in clock units, using
+///   the thread-local random source
+/// - `from_timestamp_and_rand(timestamp, &rng)`: an ID for a timestamp using a
+///   custom random source
+///
+/// ```text
+/// define_thread_local_generator!(
+///     <visibility> <TypeName>, <UlidType>,
+///     clock: <TimeSourceType> = <clock expression>,
+///     rand: <RandSourceType> = <rand expression>
+/// );
+/// ```
+///
+/// The clock expression is evaluated once, on first use, and cloned into every
+/// thread, so the clock type must implement [`Clone`]. The rand expression is
+/// evaluated once per thread and generator.
+///
+/// ## Example: A 64-bit ULID-like layout with a custom epoch
+/// ```rust
+/// use core::time::Duration;
+///
+/// use ferroid::{
+///     define_thread_local_generator, define_ulid, rand::ThreadRandom, time::MonotonicClock,
+/// };
+///
+/// define_ulid!(
+///     SmallUlid, u64,
+///     reserved: 0,
+///     timestamp: 40,
+///     random: 24
+/// );
+///
+/// const MY_EPOCH: Duration = Duration::from_millis(1_700_000_000_000);
+///
+/// define_thread_local_generator!(
+///     /// Thread-local generator for `SmallUlid`s in 4ms ticks.
+///     pub SmallUlidGen, SmallUlid,
+///     clock: MonotonicClock<4> = MonotonicClock::<4>::with_epoch(MY_EPOCH),
+///     rand: ThreadRandom = ThreadRandom
+/// );
+///
+/// let a = SmallUlidGen::new_id_mono(|_| std::thread::yield_now());
+/// let b = SmallUlidGen::new_id_mono(|_| std::thread::yield_now());
+/// assert!(a < b);
+///
+/// let id = SmallUlidGen::from_timestamp(42);
+/// assert_eq!(id.timestamp(), 42);
+/// ```
+///
+/// [`UlidId`]: crate::id::UlidId
+/// [`Ulid`]: crate::generator::thread_local::Ulid
+/// [`BasicUlidGenerator`]: crate::generator::BasicUlidGenerator
+/// [`BasicMonoUlidGenerator`]: crate::generator::BasicMonoUlidGenerator
+#[cfg_attr(docsrs, doc(cfg(feature = "thread-local")))]
+#[macro_export]
+macro_rules! define_thread_local_generator {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident, $id:ty,
+        clock: $clock:ty = $clock_init:expr,
+        rand: $rand:ty = $rand_init:expr $(,)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug)]
+        $vis struct $name;
+
+        const _: () = {
+            /// The clock shared by every thread's generators.
+            static CLOCK: $crate::__internal::LazyLock<$clock> =
+                $crate::__internal::LazyLock::new(|| $clock_init);
+
+            $crate::__internal::thread_local! {
+                static BASIC: $crate::generator::BasicUlidGenerator<$id, $clock, $rand> =
+                    $crate::generator::BasicUlidGenerator::new(CLOCK.clone(), $rand_init);
+
+                static BASIC_MONO: $crate::generator::BasicMonoUlidGenerator<$id, $clock, $rand> =
+                    $crate::generator::BasicMonoUlidGenerator::new(CLOCK.clone(), $rand_init);
+
+                static RAND: $rand = $rand_init;
+            }
+
+            impl $name {
+                /// Generates a new **non-monotonic** ID using the thread-local
+                /// generator.
+                ///
+                /// Always samples fresh randomness, so IDs are not strictly
+                /// increasing when timestamps are equal.
+                #[must_use]
+                pub fn new_id() -> $id {
+                    BASIC.with(|g| match g.poll_id() {
+                        $crate::generator::Poll::Ready { id } => id,
+                        $crate::generator::Poll::Pending { .. } => {
+                            unreachable!("basic ULID generator should never need to yield")
+                        }
+                        $crate::generator::Poll::TimestampOverflow { timestamp } => {
+                            panic!("time source timestamp {timestamp} exceeds the ID layout's maximum timestamp")
+                        }
+                    })
+                }
+
+                /// Generates a new **monotonic** ID using the thread-local
+                /// generator.
+                ///
+                /// Within a given tick, IDs are strictly increasing **per
+                /// thread**. If the random space saturates, the generator
+                /// retries using the provided callback.
+                #[must_use]
+                pub fn new_id_mono(f: impl FnMut(<$id as $crate::id::Id>::Ty)) -> $id {
+                    BASIC_MONO.with(|g| g.next_id(f))
+                }
+
+                /// Creates an ID from a timestamp in clock units, using the
+                /// thread-local random source.
+                #[must_use]
+                pub fn from_timestamp(timestamp: <$id as $crate::id::Id>::Ty) -> $id {
+                    RAND.with(|rng| Self::from_timestamp_and_rand(timestamp, rng))
+                }
+
+                /// Creates an ID from a timestamp in clock units and a custom
+                /// random source.
+                #[must_use]
+                pub fn from_timestamp_and_rand<R>(
+                    timestamp: <$id as $crate::id::Id>::Ty,
+                    rng: &R,
+                ) -> $id
+                where
+                    R: $crate::rand::RandSource<<$id as $crate::id::Id>::Ty>,
+                {
+                    <$id as $crate::id::UlidId>::from_components(timestamp, rng.rand())
+                }
+            }
+        };
+    };
+}
+
+#[cfg(test)]
+// Local `define_ulid!` expansions are linted as if hand-written.
+#[allow(clippy::wrong_self_convention)]
+mod tests {
+    use crate::{
+        define_ulid,
+        rand::RandSource,
+        time::{MonotonicClock, UNIX_EPOCH},
+    };
+
+    define_ulid!(
+        TestUlid, u64,
+        reserved: 0,
+        timestamp: 40,
+        random: 24
+    );
+
+    #[derive(Clone, Debug)]
+    struct FixedRand;
+    impl RandSource<u64> for FixedRand {
+        fn rand(&self) -> u64 {
+            0x00AB_CDEF
+        }
+    }
+
+    define_thread_local_generator!(
+        TestGen, TestUlid,
+        clock: MonotonicClock<10> = MonotonicClock::<10>::with_epoch(UNIX_EPOCH),
+        rand: FixedRand = FixedRand,
+    );
+
+    #[test]
+    fn uses_custom_rand_and_granularity() {
+        let id = TestGen::new_id();
+        assert_eq!(id.random(), 0x00AB_CDEF);
+
+        // Timestamps are in 10ms ticks since the Unix epoch.
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap();
+        let ticks = (now.as_millis() / 10) as u64;
+        assert!(id.timestamp().abs_diff(ticks) <= 1);
+
+        let id = TestGen::from_timestamp(7);
+        assert_eq!(id.timestamp(), 7);
+        assert_eq!(id.random(), 0x00AB_CDEF);
+    }
+
+    #[test]
+    fn mono_ids_are_strictly_increasing_per_thread() {
+        let mut prev = TestGen::new_id_mono(|_| std::thread::yield_now());
+        for _ in 0..10_000 {
+            let id = TestGen::new_id_mono(|_| std::thread::yield_now());
+            assert!(id > prev);
+            prev = id;
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Thread-local ID generation utilities.
+//!
+//! Provides high-performance, non-monotonic and monotonic ULID generation, as
+//! well as contention-free Snowflake generation, using thread-local
+//! generators.
+//!
+//! In rare cases where the generator saturates within the same millisecond
+//! (monotonic or sequence overflow), it yields using the configured backoff
+//! strategy (e.g., spin, yield, sleep). These overflows typically resolve
+//! within ~1ms.
+mod macros;
+#[cfg(feature = "snowflake")]
+mod snowflake;
+mod ulid;
+
+#[cfg_attr(docsrs, doc(cfg(all(feature = "thread-local", feature = "snowflake"))))]
+#[cfg(feature = "snowflake")]
+pub use snowflake::*;
+pub use ulid::*;
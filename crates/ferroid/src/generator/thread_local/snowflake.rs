@@ -0,0 +1,472 @@
+use core::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    marker::PhantomData,
+    time::Duration,
+};
+use std::{
+    boxed::Box,
+    sync::{Mutex, PoisonError},
+    thread_local,
+    vec::Vec,
+};
+
+use crate::{
+    generator::{BasicSnowflakeGenerator, Poll, timestamp_overflow},
+    id::{
+        SnowflakeDiscordId, SnowflakeId, SnowflakeInstagramId, SnowflakeMastodonId,
+        SnowflakeTwitterId, ToU64,
+    },
+    machine::{Error, checked_machine_id},
+    time::{
+        DISCORD_EPOCH, INSTAGRAM_EPOCH, MASTODON_EPOCH, MonotonicClock, TWITTER_EPOCH, TimeSource,
+    },
+};
+
+/// A [`SnowflakeId`] layout that can be generated by the thread-local
+/// [`Snowflake`] facility.
+///
+/// The only requirement is the epoch of the shared [`MonotonicClock`]. It is
+/// implemented for all built-in layouts and can be implemented for custom
+/// layouts:
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use ferroid::{
+///     define_snowflake_id,
+///     generator::thread_local::{Snowflake, ThreadLocalSnowflakeId},
+/// };
+///
+/// define_snowflake_id!(
+///     MyId, u64,
+///     reserved: 0,
+///     timestamp: 44,
+///     machine_id: 8,
+///     sequence: 12
+/// );
+///
+/// impl ThreadLocalSnowflakeId for MyId {
+///     const EPOCH: Duration = Duration::from_millis(1_700_000_000_000);
+/// }
+///
+/// let id: MyId = Snowflake::<MyId>::new_id(|_| std::thread::yield_now());
+/// ```
+pub trait ThreadLocalSnowflakeId: SnowflakeId + Send + 'static {
+    /// The epoch, as a [`Duration`] since 1970-01-01 UTC, timestamps are
+    /// measured from.
+    const EPOCH: Duration;
+}
+
+impl ThreadLocalSnowflakeId for SnowflakeTwitterId {
+    const EPOCH: Duration = TWITTER_EPOCH;
+}
+
+impl ThreadLocalSnowflakeId for SnowflakeDiscordId {
+    const EPOCH: Duration = DISCORD_EPOCH;
+}
+
+impl ThreadLocalSnowflakeId for SnowflakeInstagramId {
+    const EPOCH: Duration = INSTAGRAM_EPOCH;
+}
+
+impl ThreadLocalSnowflakeId for SnowflakeMastodonId {
+    const EPOCH: Duration = MASTODON_EPOCH;
+}
+
+/// Process-wide machine ID pools, one per layout.
+static POOLS: Mutex<Vec<(TypeId, Pool)>> = Mutex::new(Vec::new());
+
+/// Machine IDs handed out to threads for a single layout.
+///
+/// IDs are issued in increasing order and reused (most recently released
+/// first) once a thread exits. A released ID is kept with the last ID its
+/// thread issued, so that the next thread to claim it can resume after it.
+struct Pool {
+    next: u64,
+    released: Vec<(u64, Box<dyn Any + Send>)>,
+}
+
+impl Pool {
+    fn claim<ID: SnowflakeId + Send + 'static>() -> Option<(u64, Option<ID>)> {
+        let mut pools = POOLS.lock().unwrap_or_else(PoisonError::into_inner);
+        let key = TypeId::of::<ID>();
+        let index = match pools.iter().position(|(k, _)| *k == key) {
+            Some(index) => index,
+            None => {
+                pools.push((
+                    key,
+                    Self {
+                        next: 0,
+                        released: Vec::new(),
+                    },
+                ));
+                pools.len() - 1
+            }
+        };
+        let pool = &mut pools[index].1;
+
+        if let Some((machine_id, last)) = pool.released.pop() {
+            return Some((machine_id, last.downcast::<ID>().ok().map(|last| *last)));
+        }
+        if pool.next > ID::max_machine_id().to_u64() {
+            return None;
+        }
+        let machine_id = pool.next;
+        pool.next += 1;
+        Some((machine_id, None))
+    }
+
+    fn release<ID: Send + 'static>(machine_id: u64, last: ID) {
+        let mut pools = POOLS.lock().unwrap_or_else(PoisonError::into_inner);
+        let key = TypeId::of::<ID>();
+        if let Some((_, pool)) = pools.iter_mut().find(|(k, _)| *k == key) {
+            pool.released.push((machine_id, Box::new(last)));
+        }
+    }
+}
+
+/// A thread's generator for one layout; returns its machine ID to the pool
+/// when the thread exits.
+struct Lane<ID, T>
+where
+    ID: ThreadLocalSnowflakeId,
+    T: TimeSource<ID::Ty>,
+{
+    generator: BasicSnowflakeGenerator<ID, T>,
+    machine_id: u64,
+}
+
+impl<ID, T> Drop for Lane<ID, T>
+where
+    ID: ThreadLocalSnowflakeId,
+    T: TimeSource<ID::Ty>,
+{
+    fn drop(&mut self) {
+        Pool::release::<ID>(self.machine_id, self.generator.last_id());
+    }
+}
+
+thread_local! {
+    /// This thread's lanes, keyed by layout. Most programs use a single
+    /// layout, so a linear scan beats hashing.
+    static LANES: RefCell<Vec<(TypeId, Box<dyn Any>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A thread-local Snowflake generator for the layout `ID`.
+///
+/// The first time a thread generates an `ID`, it claims a machine ID from a
+/// process-wide pool covering `0..=ID::max_machine_id()` and creates its own
+/// [`BasicSnowflakeGenerator`] reading a shared [`MonotonicClock`]. Threads
+/// never share generator state, so there is no contention. When the thread
+/// exits, its machine ID is returned to the pool for reuse.
+///
+/// IDs are unique within the process and strictly increasing **per thread**.
+/// A thread that reclaims a released machine ID resumes after the last ID
+/// issued with it, so reuse within the same tick cannot repeat an ID.
+/// The pool only coordinates threads of this process. If several processes
+/// generate the same layout their IDs can collide; use explicit generators with
+/// a [`MachineIdProvider`] instead.
+///
+/// At most `ID::max_machine_id() + 1` threads can generate an `ID` at the same
+/// time. Further threads receive [`Error::Exhausted`] from
+/// [`Snowflake::try_new_id`] until a thread holding a machine ID exits.
+///
+/// # Example
+/// ```
+/// use ferroid::{generator::thread_local::Snowflake, id::SnowflakeTwitterId};
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         std::thread::spawn(|| {
+///             Snowflake::<SnowflakeTwitterId>::new_id(|_| std::thread::yield_now())
+///         })
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     let id = handle.join().unwrap();
+///     assert!(id.machine_id() <= SnowflakeTwitterId::max_machine_id());
+/// }
+/// ```
+///
+/// [`MachineIdProvider`]: crate::machine::MachineIdProvider
+pub struct Snowflake<ID> {
+    _id: PhantomData<ID>,
+}
+
+impl<ID> Snowflake<ID>
+where
+    ID: ThreadLocalSnowflakeId,
+    MonotonicClock: TimeSource<ID::Ty>,
+{
+    /// Generates a new Snowflake ID using this thread's generator.
+    ///
+    /// If the sequence is exhausted for the current millisecond, the generator
+    /// retries using the provided callback.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this thread has no machine ID yet and every machine ID of the
+    /// layout is held by another thread. Use [`Self::try_new_id`] to handle
+    /// that case.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{generator::thread_local::Snowflake, id::SnowflakeTwitterId};
+    ///
+    /// let a = Snowflake::<SnowflakeTwitterId>::new_id(|_| std::thread::yield_now());
+    /// let b = Snowflake::<SnowflakeTwitterId>::new_id(|_| std::thread::yield_now());
+    /// assert!(a < b);
+    /// ```
+    #[must_use]
+    pub fn new_id(f: impl FnMut(ID::Ty)) -> ID {
+        match Self::try_new_id(f) {
+            Ok(id) => id,
+            Err(e) => panic!("failed to claim a thread-local machine ID: {e}"),
+        }
+    }
+
+    /// Generates a new Snowflake ID using this thread's generator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Exhausted`] if this thread has no machine ID yet and
+    /// every machine ID of the layout is held by another thread.
+    pub fn try_new_id(mut f: impl FnMut(ID::Ty)) -> Result<ID, Error> {
+        loop {
+            // Yield outside of the lane borrow so `f` may use other layouts.
+            match Self::with_lane(|lane| lane.generator.poll_id())? {
+                Poll::Ready { id } => break Ok(id),
+                Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
+            }
+        }
+    }
+
+    /// Attempts to generate a new Snowflake ID using this thread's generator.
+    ///
+    /// Returns [`Poll::Pending`] if the sequence is exhausted for the current
+    /// millisecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Exhausted`] if this thread has no machine ID yet and
+    /// every machine ID of the layout is held by another thread.
+    pub fn try_poll_id() -> Result<Poll<ID>, Error> {
+        Self::with_lane(|lane| lane.generator.poll_id())
+    }
+
+    /// Returns the machine ID claimed by this thread, claiming one if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Exhausted`] if this thread has no machine ID yet and
+    /// every machine ID of the layout is held by another thread.
+    pub fn machine_id() -> Result<ID::Ty, Error> {
+        Self::with_lane(|lane| lane.machine_id)
+            .and_then(|machine_id| checked_machine_id::<ID>(machine_id).ok_or(Error::OutOfRange))
+    }
+
+    fn with_lane<R>(f: impl FnOnce(&Lane<ID, MonotonicClock>) -> R) -> Result<R, Error> {
+        Self::with_lane_using(|| MonotonicClock::with_epoch(ID::EPOCH), f)
+    }
+
+    /// Runs `f` on this thread's lane, creating it with the time source from
+    /// `time` if needed.
+    fn with_lane_using<T, R>(
+        time: impl FnOnce() -> T,
+        f: impl FnOnce(&Lane<ID, T>) -> R,
+    ) -> Result<R, Error>
+    where
+        T: TimeSource<ID::Ty> + 'static,
+    {
+        let key = TypeId::of::<ID>();
+        LANES.with(|lanes| {
+            if let Some(lane) = Self::find(&lanes.borrow(), key) {
+                return Ok(f(lane));
+            }
+
+            let (machine_id, last) = Pool::claim::<ID>().ok_or(Error::Exhausted)?;
+            let lane = Lane::<ID, T> {
+                generator: BasicSnowflakeGenerator::new(
+                    checked_machine_id::<ID>(machine_id).ok_or(Error::OutOfRange)?,
+                    time(),
+                ),
+                machine_id,
+            };
+            if let Some(last) = last {
+                lane.generator.advance_to(last);
+            }
+            lanes.borrow_mut().push((key, Box::new(lane)));
+            let lanes = lanes.borrow();
+            Ok(f(Self::find(&lanes, key).expect("lane was just inserted")))
+        })
+    }
+
+    fn find<T>(lanes: &[(TypeId, Box<dyn Any>)], key: TypeId) -> Option<&Lane<ID, T>>
+    where
+        T: TimeSource<ID::Ty> + 'static,
+    {
+        lanes
+            .iter()
+            .find(|(k, _)| *k == key)
+            .and_then(|(_, lane)| lane.downcast_ref::<Lane<ID, T>>())
+    }
+}
+
+#[cfg(test)]
+// Local `define_snowflake_id!` expansions are linted as if hand-written.
+#[allow(clippy::wrong_self_convention)]
+mod tests {
+    use std::{sync::mpsc, thread};
+
+    use super::*;
+    use crate::define_snowflake_id;
+
+    // Dedicated layouts so the pools are not shared with other tests.
+    define_snowflake_id!(
+        TwoMachineId, u64,
+        reserved: 0,
+        timestamp: 50,
+        machine_id: 1,
+        sequence: 13
+    );
+
+    impl ThreadLocalSnowflakeId for TwoMachineId {
+        const EPOCH: Duration = TWITTER_EPOCH;
+    }
+
+    define_snowflake_id!(
+        ManyMachineId, u64,
+        reserved: 0,
+        timestamp: 44,
+        machine_id: 8,
+        sequence: 12
+    );
+
+    impl ThreadLocalSnowflakeId for ManyMachineId {
+        const EPOCH: Duration = TWITTER_EPOCH;
+    }
+
+    define_snowflake_id!(
+        OneMachineId, u64,
+        reserved: 0,
+        timestamp: 54,
+        machine_id: 0,
+        sequence: 10
+    );
+
+    impl ThreadLocalSnowflakeId for OneMachineId {
+        const EPOCH: Duration = TWITTER_EPOCH;
+    }
+
+    #[test]
+    fn ids_are_increasing_per_thread_and_use_the_claimed_machine_id() {
+        let machine_id = Snowflake::<ManyMachineId>::machine_id().unwrap();
+        let mut last = Snowflake::<ManyMachineId>::new_id(|_| thread::yield_now());
+        for _ in 0..10_000 {
+            let id = Snowflake::<ManyMachineId>::new_id(|_| thread::yield_now());
+            assert!(id > last);
+            assert_eq!(id.machine_id(), machine_id);
+            last = id;
+        }
+    }
+
+    #[test]
+    fn concurrent_threads_claim_distinct_machine_ids() {
+        let (tx, rx) = mpsc::channel();
+        let barrier = std::sync::Arc::new(std::sync::Barrier::new(16));
+        let handles: Vec<_> = (0..16)
+            .map(|_| {
+                let tx = tx.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    let id = Snowflake::<ManyMachineId>::new_id(|_| thread::yield_now());
+                    tx.send(id.machine_id()).unwrap();
+                    // Keep every machine ID claimed until all threads have one.
+                    barrier.wait();
+                })
+            })
+            .collect();
+        drop(tx);
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut machine_ids: Vec<_> = rx.iter().collect();
+        machine_ids.sort_unstable();
+        machine_ids.dedup();
+        assert_eq!(machine_ids.len(), 16);
+    }
+
+    #[test]
+    fn machine_ids_are_exhausted_and_released_on_thread_exit() {
+        let spawn_holder = || {
+            let (claimed_tx, claimed_rx) = mpsc::channel();
+            let (exit_tx, exit_rx) = mpsc::channel::<()>();
+            let handle = thread::spawn(move || {
+                claimed_tx
+                    .send(Snowflake::<TwoMachineId>::machine_id())
+                    .unwrap();
+                let _ = exit_rx.recv();
+            });
+            (claimed_rx.recv().unwrap(), exit_tx, handle)
+        };
+
+        let (a, exit_a, handle_a) = spawn_holder();
+        let (b, exit_b, handle_b) = spawn_holder();
+        let (a, b) = (a.unwrap(), b.unwrap());
+        assert_ne!(a, b);
+
+        let exhausted = thread::spawn(|| Snowflake::<TwoMachineId>::try_new_id(|_| {}))
+            .join()
+            .unwrap();
+        assert_eq!(exhausted, Err(Error::Exhausted));
+
+        drop(exit_a);
+        handle_a.join().unwrap();
+        let reused = thread::spawn(Snowflake::<TwoMachineId>::machine_id)
+            .join()
+            .unwrap();
+        assert_eq!(reused, Ok(a));
+
+        drop(exit_b);
+        handle_b.join().unwrap();
+    }
+
+    #[test]
+    fn reclaimed_machine_id_resumes_after_the_exited_thread() {
+        // Every thread reads the same tick, so a reclaimed machine ID that
+        // restarted its sequence would repeat the previous thread's IDs.
+        let issue_ids = || {
+            thread::spawn(|| {
+                (0..5)
+                    .map(|_| {
+                        Snowflake::<OneMachineId>::with_lane_using(
+                            || || 100_u64,
+                            |lane| lane.generator.poll_id(),
+                        )
+                        .unwrap()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .join()
+            .unwrap()
+        };
+
+        let ready = |poll| match poll {
+            Poll::Ready { id } => id,
+            poll => panic!("expected a ready ID, got {poll:?}"),
+        };
+        let first: Vec<OneMachineId> = issue_ids().into_iter().map(ready).collect();
+        let second: Vec<OneMachineId> = issue_ids().into_iter().map(ready).collect();
+
+        let mut ids: Vec<_> = first.iter().chain(&second).copied().collect();
+        assert!(ids.iter().all(|id| id.timestamp() == 100));
+        assert!(second[0] > first[4]);
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), 10);
+    }
+}
@@ -1,15 +1,7 @@
-//! Thread-local ULID generation utilities.
-//!
-//! Provides high-performance, non-monotonic and monotonic ULID generation using
-//! thread-local generators.
-//!
-//! In rare cases where the generator saturates within the same millisecond
-//! (monotonic overflow), it yields using the configured backoff strategy (e.g.,
-//! spin, yield, sleep). These overflows typically resolve within ~1ms.
 use std::{sync::LazyLock, thread_local};
 
 use crate::{
-    generator::{BasicMonoUlidGenerator, BasicUlidGenerator, Poll},
+    generator::{BasicMonoUlidGenerator, BasicUlidGenerator, Poll, timestamp_overflow},
     id::{Id, ULID},
     rand::{RandSource, ThreadRandom},
     time::{MonotonicClock, UNIX_EPOCH},
@@ -70,6 +62,7 @@ impl Ulid {
         BASIC_ULID.with(|g| match g.poll_id() {
             Poll::Ready { id } => id,
             Poll::Pending { .. } => unreachable!("basic ULID generator should never need to yield"),
+            Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
         })
     }
 
@@ -5,7 +5,10 @@ use portable_atomic::{AtomicU128, Ordering};
 use tracing::instrument;
 
 use crate::{
-    generator::{Poll, Result, UlidGenerator},
+    generator::{
+        Counters, Poll, RandomIncrement, Result, UlidGenerator, advance_ulid_target,
+        assert_node_fits, timestamp_overflow,
+    },
     id::UlidId,
     rand::RandSource,
     time::TimeSource,
@@ -52,6 +55,9 @@ where
     state: AtomicU128,
     time: T,
     rng: R,
+    node: ID::Ty,
+    increment: RandomIncrement<ID>,
+    stats: Counters,
     _id: PhantomData<ID>,
 }
 
@@ -113,7 +119,29 @@ where
     /// In typical use cases, you should prefer [`Self::new`] to let the
     /// generator initialize itself from the current time.
     pub fn from_components(timestamp: ID::Ty, random: ID::Ty, time: T, rng: R) -> Self {
-        let id = ID::from_components(timestamp, random);
+        Self::from_id(ID::from_components(timestamp, random), time, rng)
+    }
+
+    /// Creates a new [`AtomicMonoUlidGenerator`] that tags every ID with
+    /// `node`.
+    ///
+    /// See [`BasicMonoUlidGenerator::with_node`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` exceeds [`UlidId::max_node`].
+    ///
+    /// [`BasicMonoUlidGenerator::with_node`]: crate::generator::BasicMonoUlidGenerator::with_node
+    pub fn with_node(node: ID::Ty, time: T, rng: R) -> Self {
+        assert_node_fits::<ID>(node);
+        Self::from_id(
+            ID::from_node_components(ID::ZERO, node, ID::ZERO),
+            time,
+            rng,
+        )
+    }
+
+    fn from_id(id: ID, time: T, rng: R) -> Self {
         Self {
             #[cfg(feature = "cache-padded")]
             state: crossbeam_utils::CachePadded::new(AtomicU128::new(id.to_raw())),
@@ -121,10 +149,29 @@ where
             state: AtomicU128::new(id.to_raw()),
             time,
             rng,
+            node: id.node(),
+            increment: RandomIncrement::One,
+            stats: Counters::new(),
             _id: PhantomData,
         }
     }
 
+    /// Increments the random component by a random amount drawn from `range`,
+    /// instead of by one, for IDs issued within the same tick.
+    ///
+    /// See [`BasicMonoUlidGenerator::with_random_increment`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty or starts at zero.
+    ///
+    /// [`BasicMonoUlidGenerator::with_random_increment`]: crate::generator::BasicMonoUlidGenerator::with_random_increment
+    #[must_use]
+    pub fn with_random_increment(mut self, range: core::ops::RangeInclusive<ID::Ty>) -> Self {
+        self.increment = RandomIncrement::new(range);
+        self
+    }
+
     /// Generates a new ULID.
     ///
     /// Returns a new, time-ordered, unique ID.
@@ -149,6 +196,7 @@ where
             match self.poll_id() {
                 Poll::Ready { id } => break id,
                 Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
             }
         }
     }
@@ -176,6 +224,7 @@ where
     ///     match generator.poll_id() {
     ///         Poll::Ready { id } => break id,
     ///         Poll::Pending { .. } => std::thread::yield_now(),
+    ///         Poll::TimestampOverflow { .. } => panic!("clock overflowed the ID layout"),
     ///     }
     /// };
     /// ```
@@ -189,15 +238,19 @@ where
 
         let next_id = match now.cmp(&current_ts) {
             cmp::Ordering::Equal => {
-                if current_id.has_random_room() {
-                    current_id.increment_random()
+                if let Some(next_id) = self.increment.next(current_id, &self.rng) {
+                    next_id
                 } else {
+                    self.stats.exhausted();
                     return Poll::Pending { yield_for: ID::ONE };
                 }
             }
+            cmp::Ordering::Greater if now > ID::max_timestamp() => {
+                return Poll::TimestampOverflow { timestamp: now };
+            }
             cmp::Ordering::Greater => current_id.rollover_to_timestamp(now, self.rng.rand()),
             cmp::Ordering::Less => {
-                return Self::cold_clock_behind(now, current_ts);
+                return self.cold_clock_behind(now, current_ts);
             }
         };
 
@@ -208,21 +261,52 @@ where
             .compare_exchange(current_raw, next_raw, Ordering::Relaxed, Ordering::Relaxed)
             .is_ok()
         {
+            self.stats.issued();
             Poll::Ready { id: next_id }
         } else {
             // CAS failed - another thread won the race. Yield 0 to retry
             // immediately.
+            self.stats.contention();
             Poll::Pending {
                 yield_for: ID::ZERO,
             }
         }
     }
 
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`.
+    ///
+    /// See [`BasicMonoUlidGenerator::advance_to`] for details.
+    ///
+    /// [`BasicMonoUlidGenerator::advance_to`]: crate::generator::BasicMonoUlidGenerator::advance_to
+    pub fn advance_to(&self, id: ID) {
+        let target = advance_ulid_target(id, self.node);
+        self.state.fetch_max(target.to_raw(), Ordering::Relaxed);
+    }
+
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    pub fn last_id(&self) -> ID {
+        ID::from_raw(self.state.load(Ordering::Relaxed))
+    }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
+
     #[cold]
     #[inline(never)]
-    fn cold_clock_behind(now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
+    fn cold_clock_behind(&self, now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
         let yield_for = current_ts - now;
         debug_assert!(yield_for >= ID::ZERO);
+        self.stats.clock_behind(yield_for);
         Poll::Pending { yield_for }
     }
 }
@@ -4,7 +4,7 @@ use core::marker::PhantomData;
 use tracing::instrument;
 
 use crate::{
-    generator::{Poll, Result, UlidGenerator},
+    generator::{Counters, Poll, Result, UlidGenerator, assert_node_fits, timestamp_overflow},
     id::UlidId,
     rand::RandSource,
     time::TimeSource,
@@ -41,6 +41,8 @@ where
 {
     time: T,
     rng: R,
+    node: ID::Ty,
+    stats: Counters,
     _id: PhantomData<ID>,
 }
 
@@ -81,10 +83,30 @@ where
         Self {
             time,
             rng,
+            node: ID::ZERO,
+            stats: Counters::new(),
             _id: PhantomData,
         }
     }
 
+    /// Creates a new [`BasicUlidGenerator`] that tags every ID with `node`.
+    ///
+    /// The node occupies the layout's node field (see [`define_ulid!`]), so
+    /// IDs from generators with different nodes never collide.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` exceeds [`UlidId::max_node`].
+    ///
+    /// [`define_ulid!`]: crate::define_ulid
+    pub fn with_node(node: ID::Ty, time: T, rng: R) -> Self {
+        assert_node_fits::<ID>(node);
+        Self {
+            node,
+            ..Self::new(time, rng)
+        }
+    }
+
     /// Generates a new ULID.
     ///
     /// Returns a new, time-ordered, unique ID.
@@ -108,6 +130,7 @@ where
             match self.poll_id() {
                 Poll::Ready { id } => break id,
                 Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
             }
         }
     }
@@ -133,15 +156,32 @@ where
     ///     match generator.poll_id() {
     ///         Poll::Ready { id } => break id,
     ///         Poll::Pending { .. } => unreachable!(),
+    ///         Poll::TimestampOverflow { .. } => panic!("clock overflowed the ID layout"),
     ///     }
     /// };
     /// ```
     #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self)))]
     pub fn poll_id(&self) -> Poll<ID> {
+        let now = self.time.current_millis();
+        if now > ID::max_timestamp() {
+            return Poll::TimestampOverflow { timestamp: now };
+        }
+        self.stats.issued();
         Poll::Ready {
-            id: ID::from_components(self.time.current_millis(), self.rng.rand()),
+            id: ID::from_node_components(now, self.node, self.rng.rand()),
         }
     }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
 }
 
 impl<ID, T, R> UlidGenerator<ID, T, R> for BasicUlidGenerator<ID, T, R>
@@ -4,7 +4,10 @@ use core::{cell::Cell, cmp::Ordering};
 use tracing::instrument;
 
 use crate::{
-    generator::{Poll, Result, UlidGenerator},
+    generator::{
+        Counters, Poll, RandomIncrement, Result, UlidGenerator, advance_ulid_target,
+        assert_node_fits, timestamp_overflow,
+    },
     id::UlidId,
     rand::RandSource,
     time::TimeSource,
@@ -42,6 +45,9 @@ where
     state: Cell<ID>,
     time: T,
     rng: R,
+    node: ID::Ty,
+    increment: RandomIncrement<ID>,
+    stats: Counters,
 }
 
 impl<ID, T, R> BasicMonoUlidGenerator<ID, T, R>
@@ -101,14 +107,99 @@ where
     /// In typical use cases, you should prefer [`Self::new`] to let the
     /// generator initialize itself from the current time.
     pub fn from_components(timestamp: ID::Ty, random: ID::Ty, time: T, rng: R) -> Self {
-        let id = ID::from_components(timestamp, random);
+        Self::from_id(ID::from_components(timestamp, random), time, rng)
+    }
+
+    /// Creates a new [`BasicMonoUlidGenerator`] that tags every ID with
+    /// `node`.
+    ///
+    /// The node occupies the layout's node field (see [`define_ulid!`]), so
+    /// IDs from generators with different nodes can never collide, while IDs
+    /// from one generator stay strictly increasing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` exceeds [`UlidId::max_node`].
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     define_ulid,
+    ///     generator::BasicMonoUlidGenerator,
+    ///     id::UlidId,
+    ///     rand::ThreadRandom,
+    ///     time::MonotonicClock,
+    /// };
+    ///
+    /// define_ulid!(
+    ///     NodeUlid, u128,
+    ///     reserved: 0,
+    ///     timestamp: 48,
+    ///     node: 8,
+    ///     random: 72
+    /// );
+    ///
+    /// let generator = BasicMonoUlidGenerator::with_node(3, MonotonicClock::default(), ThreadRandom::default());
+    ///
+    /// let id: NodeUlid = generator.next_id(|_| std::thread::yield_now());
+    /// assert_eq!(id.node(), 3);
+    /// ```
+    ///
+    /// [`define_ulid!`]: crate::define_ulid
+    pub fn with_node(node: ID::Ty, time: T, rng: R) -> Self {
+        assert_node_fits::<ID>(node);
+        Self::from_id(
+            ID::from_node_components(ID::ZERO, node, ID::ZERO),
+            time,
+            rng,
+        )
+    }
+
+    fn from_id(id: ID, time: T, rng: R) -> Self {
         Self {
             state: Cell::new(id),
             time,
             rng,
+            node: id.node(),
+            increment: RandomIncrement::One,
+            stats: Counters::new(),
         }
     }
 
+    /// Increments the random component by a random amount drawn from `range`,
+    /// instead of by one, for IDs issued within the same tick.
+    ///
+    /// IDs remain strictly increasing, but knowing one ID no longer reveals
+    /// the next ones in its tick. Larger steps exhaust the random component
+    /// sooner; when the drawn step does not fit, [`Self::poll_id`] returns
+    /// [`Poll::Pending`] until the next tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty or starts at zero.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::BasicMonoUlidGenerator,
+    ///     id::{ULID, UlidId},
+    ///     rand::ThreadRandom,
+    /// };
+    ///
+    /// let generator = BasicMonoUlidGenerator::<ULID, _, _>::new(|| 100_u128, ThreadRandom::default())
+    ///     .with_random_increment(1..=1 << 32);
+    ///
+    /// let first = generator.next_id(|_| std::thread::yield_now());
+    /// let second = generator.next_id(|_| std::thread::yield_now());
+    /// assert!(second > first);
+    /// assert!(second.random() - first.random() <= 1 << 32);
+    /// ```
+    #[must_use]
+    pub fn with_random_increment(mut self, range: core::ops::RangeInclusive<ID::Ty>) -> Self {
+        self.increment = RandomIncrement::new(range);
+        self
+    }
+
     /// Generates a new ULID.
     ///
     /// Returns a new, time-ordered, unique ID.
@@ -132,6 +223,7 @@ where
             match self.poll_id() {
                 Poll::Ready { id } => break id,
                 Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
             }
         }
     }
@@ -157,6 +249,7 @@ where
     ///     match generator.poll_id() {
     ///         Poll::Ready { id } => break id,
     ///         Poll::Pending { .. } => std::thread::yield_now(),
+    ///         Poll::TimestampOverflow { .. } => panic!("clock overflowed the ID layout"),
     ///     }
     /// };
     /// ```
@@ -168,30 +261,86 @@ where
 
         match now.cmp(&current_ts) {
             Ordering::Equal => {
-                if state.has_random_room() {
-                    let updated = state.increment_random();
+                if let Some(updated) = self.increment.next(state, &self.rng) {
                     self.state.set(updated);
+                    self.stats.issued();
                     Poll::Ready { id: updated }
                 } else {
+                    self.stats.exhausted();
                     Poll::Pending { yield_for: ID::ONE }
                 }
             }
+            Ordering::Greater if now > ID::max_timestamp() => {
+                Poll::TimestampOverflow { timestamp: now }
+            }
             Ordering::Greater => {
                 // Set the new timestamp and random number.
                 let rand = self.rng.rand();
                 let updated = state.rollover_to_timestamp(now, rand);
                 self.state.set(updated);
+                self.stats.issued();
                 Poll::Ready { id: updated }
             }
-            Ordering::Less => Self::cold_clock_behind(now, current_ts),
+            Ordering::Less => self.cold_clock_behind(now, current_ts),
+        }
+    }
+
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`.
+    ///
+    /// This is meant for resuming after a failover, e.g. from `MAX(id)` read
+    /// back from a database. The state never moves backwards, so an older `id`
+    /// is a no-op. Within `id`'s tick, subsequent IDs increment its random
+    /// component. If `id` is ahead of the time source, the generator returns
+    /// [`Poll::Pending`] until the clock catches up.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::BasicMonoUlidGenerator,
+    ///     id::{ULID, UlidId},
+    ///     rand::ThreadRandom,
+    /// };
+    ///
+    /// let generator = BasicMonoUlidGenerator::<ULID, _, _>::new(|| 100_u128, ThreadRandom::default());
+    ///
+    /// // `MAX(id)` read back from the database after a failover.
+    /// let max_id = ULID::from_components(100, 42);
+    /// generator.advance_to(max_id);
+    ///
+    /// let id = generator.next_id(|_| std::thread::yield_now());
+    /// assert!(id > max_id);
+    /// ```
+    pub fn advance_to(&self, id: ID) {
+        let target = advance_ulid_target(id, self.node);
+        if target > self.state.get() {
+            self.state.set(target);
         }
     }
 
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    pub fn last_id(&self) -> ID {
+        self.state.get()
+    }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
+
     #[cold]
     #[inline(never)]
-    fn cold_clock_behind(now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
+    fn cold_clock_behind(&self, now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
         let yield_for = current_ts - now;
         debug_assert!(yield_for >= ID::ZERO);
+        self.stats.clock_behind(yield_for);
         Poll::Pending { yield_for }
     }
 }
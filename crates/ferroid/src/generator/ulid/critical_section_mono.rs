@@ -0,0 +1,392 @@
+use core::{cell::Cell, cmp::Ordering};
+
+use critical_section::Mutex;
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+use crate::{
+    generator::{
+        Counters, Poll, RandomIncrement, Result, UlidGenerator, advance_ulid_target,
+        assert_node_fits, timestamp_overflow,
+    },
+    id::UlidId,
+    rand::RandSource,
+    time::TimeSource,
+};
+
+/// A *monotonic* ULID-style ID generator that guards its state with a
+/// [`critical_section`], for sharing across cores without `std` or an
+/// allocator.
+///
+/// The state lives inline in a [`critical_section::Mutex`], so any layout,
+/// including 128-bit ULIDs on targets without 128-bit atomics, can be shared
+/// from a `static`. The time source is read outside the critical section; the
+/// RNG is only drawn inside it.
+///
+/// The final binary must provide a critical-section implementation, e.g. from
+/// a HAL, a `cortex-m` single-core feature, or `critical-section/std` on
+/// hosted targets.
+///
+/// ## Features
+/// - ✅ Thread-safe
+/// - ✅ Probabilistically unique (no coordination required)
+/// - ✅ Time-ordered (monotonically increasing per time-source tick)
+/// - ✅ `no_std` without `alloc`
+///
+/// ## Recommended When
+/// - You're on a `no_std` multi-core target or in a kernel
+/// - You need monotonically increasing IDs (IDs generated within the same
+///   time-source tick increment the random component)
+/// - Your target doesn't support 128-bit atomics
+///
+/// ## See Also
+/// - [`BasicMonoUlidGenerator`]
+/// - [`LockMonoUlidGenerator`]
+/// - [`SeqLockMonoUlidGenerator`]
+///
+/// [`BasicMonoUlidGenerator`]: crate::generator::BasicMonoUlidGenerator
+/// [`LockMonoUlidGenerator`]: crate::generator::LockMonoUlidGenerator
+/// [`SeqLockMonoUlidGenerator`]: crate::generator::SeqLockMonoUlidGenerator
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     generator::CriticalSectionMonoUlidGenerator,
+///     id::ULID,
+///     rand::ThreadRandom,
+///     time::MonotonicClock,
+/// };
+///
+/// let generator: CriticalSectionMonoUlidGenerator<ULID, _, _> =
+///     CriticalSectionMonoUlidGenerator::new(MonotonicClock::default(), ThreadRandom::default());
+///
+/// std::thread::scope(|scope| {
+///     for _ in 0..4 {
+///         scope.spawn(|| generator.next_id(|_| std::thread::yield_now()));
+///     }
+/// });
+/// ```
+pub struct CriticalSectionMonoUlidGenerator<ID, T, R>
+where
+    ID: UlidId,
+    T: TimeSource<ID::Ty>,
+    R: RandSource<ID::Ty>,
+{
+    state: Mutex<Cell<ID>>,
+    time: T,
+    rng: R,
+    node: ID::Ty,
+    increment: RandomIncrement<ID>,
+    stats: Counters,
+}
+
+impl<ID, T, R> CriticalSectionMonoUlidGenerator<ID, T, R>
+where
+    ID: UlidId,
+    T: TimeSource<ID::Ty>,
+    R: RandSource<ID::Ty>,
+{
+    /// Creates a new [`CriticalSectionMonoUlidGenerator`] with the provided
+    /// time source and RNG.
+    ///
+    /// # Parameters
+    /// - `time`: A [`TimeSource`] used to retrieve the current timestamp
+    /// - `rng`: A [`RandSource`] used to generate random bits
+    ///
+    /// # Returns
+    /// A ready-to-use ULID generator suitable for producing unique, sortable
+    /// IDs.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::{CriticalSectionMonoUlidGenerator, Poll},
+    ///     id::ULID,
+    ///     rand::ThreadRandom,
+    ///     time::MonotonicClock,
+    /// };
+    ///
+    /// let generator = CriticalSectionMonoUlidGenerator::new(MonotonicClock::default(), ThreadRandom::default());
+    ///
+    /// let id: ULID = generator.next_id(|_| std::thread::yield_now());
+    /// ```
+    ///
+    /// [`TimeSource`]: crate::time::TimeSource
+    /// [`RandSource`]: crate::rand::RandSource
+    pub fn new(time: T, rng: R) -> Self {
+        Self::from_components(ID::ZERO, ID::ZERO, time, rng)
+    }
+
+    /// Creates a new ID generator from explicit component values.
+    ///
+    /// This constructor is primarily useful for advanced use cases such as
+    /// restoring state from persistent storage or controlling the starting
+    /// point of the generator manually.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The initial timestamp component (usually in
+    ///   time-source units)
+    /// - `random`: The initial random component
+    /// - `time`: A [`TimeSource`] implementation used to fetch the current time
+    /// - `rng`: A [`RandSource`] used to generate future random bits
+    ///
+    /// # Returns
+    /// A new generator instance preloaded with the given state.
+    ///
+    /// # ⚠️ Note
+    /// In typical use cases, you should prefer [`Self::new`] to let the
+    /// generator initialize itself from the current time.
+    pub fn from_components(timestamp: ID::Ty, random: ID::Ty, time: T, rng: R) -> Self {
+        Self::from_id(ID::from_components(timestamp, random), time, rng)
+    }
+
+    /// Creates a new [`CriticalSectionMonoUlidGenerator`] that tags every ID with
+    /// `node`.
+    ///
+    /// The node occupies the layout's node field (see [`define_ulid!`]), so
+    /// IDs from generators with different nodes can never collide, while IDs
+    /// from one generator stay strictly increasing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` exceeds [`UlidId::max_node`].
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     define_ulid,
+    ///     generator::CriticalSectionMonoUlidGenerator,
+    ///     id::UlidId,
+    ///     rand::ThreadRandom,
+    ///     time::MonotonicClock,
+    /// };
+    ///
+    /// define_ulid!(
+    ///     NodeUlid, u128,
+    ///     reserved: 0,
+    ///     timestamp: 48,
+    ///     node: 8,
+    ///     random: 72
+    /// );
+    ///
+    /// let generator = CriticalSectionMonoUlidGenerator::with_node(3, MonotonicClock::default(), ThreadRandom::default());
+    ///
+    /// let id: NodeUlid = generator.next_id(|_| std::thread::yield_now());
+    /// assert_eq!(id.node(), 3);
+    /// ```
+    ///
+    /// [`define_ulid!`]: crate::define_ulid
+    pub fn with_node(node: ID::Ty, time: T, rng: R) -> Self {
+        assert_node_fits::<ID>(node);
+        Self::from_id(
+            ID::from_node_components(ID::ZERO, node, ID::ZERO),
+            time,
+            rng,
+        )
+    }
+
+    fn from_id(id: ID, time: T, rng: R) -> Self {
+        Self {
+            state: Mutex::new(Cell::new(id)),
+            time,
+            rng,
+            node: id.node(),
+            increment: RandomIncrement::One,
+            stats: Counters::new(),
+        }
+    }
+
+    /// Increments the random component by a random amount drawn from `range`,
+    /// instead of by one, for IDs issued within the same tick.
+    ///
+    /// IDs remain strictly increasing, but knowing one ID no longer reveals
+    /// the next ones in its tick. Larger steps exhaust the random component
+    /// sooner; when the drawn step does not fit, [`Self::poll_id`] returns
+    /// [`Poll::Pending`] until the next tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty or starts at zero.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::CriticalSectionMonoUlidGenerator,
+    ///     id::{ULID, UlidId},
+    ///     rand::ThreadRandom,
+    /// };
+    ///
+    /// let generator = CriticalSectionMonoUlidGenerator::<ULID, _, _>::new(|| 100_u128, ThreadRandom::default())
+    ///     .with_random_increment(1..=1 << 32);
+    ///
+    /// let first = generator.next_id(|_| std::thread::yield_now());
+    /// let second = generator.next_id(|_| std::thread::yield_now());
+    /// assert!(second > first);
+    /// assert!(second.random() - first.random() <= 1 << 32);
+    /// ```
+    #[must_use]
+    pub fn with_random_increment(mut self, range: core::ops::RangeInclusive<ID::Ty>) -> Self {
+        self.increment = RandomIncrement::new(range);
+        self
+    }
+
+    /// Generates a new ULID.
+    ///
+    /// Returns a new, time-ordered, unique ID.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::{CriticalSectionMonoUlidGenerator, Poll},
+    ///     id::ULID,
+    ///     rand::ThreadRandom,
+    ///     time::MonotonicClock,
+    /// };
+    ///
+    /// let generator = CriticalSectionMonoUlidGenerator::new(MonotonicClock::default(), ThreadRandom::default());
+    ///
+    /// let id: ULID = generator.next_id(|_| std::thread::yield_now());
+    /// ```
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self, f)))]
+    pub fn next_id(&self, mut f: impl FnMut(ID::Ty)) -> ID {
+        loop {
+            match self.poll_id() {
+                Poll::Ready { id } => break id,
+                Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
+            }
+        }
+    }
+
+    /// Generates a new ULID.
+    ///
+    /// Returns a new, time-ordered, unique ID if generation succeeds. If the
+    /// generator is temporarily exhausted (e.g., the random component is
+    /// exhausted and the time has not advanced), it returns [`Poll::Pending`].
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::{CriticalSectionMonoUlidGenerator, Poll},
+    ///     id::ULID,
+    ///     rand::ThreadRandom,
+    ///     time::MonotonicClock,
+    /// };
+    ///
+    /// let generator = CriticalSectionMonoUlidGenerator::new(MonotonicClock::default(), ThreadRandom::default());
+    ///
+    /// let id: ULID = loop {
+    ///     match generator.poll_id() {
+    ///         Poll::Ready { id } => break id,
+    ///         Poll::Pending { .. } => std::thread::yield_now(),
+    ///         Poll::TimestampOverflow { .. } => panic!("clock overflowed the ID layout"),
+    ///     }
+    /// };
+    /// ```
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self)))]
+    pub fn poll_id(&self) -> Poll<ID> {
+        let now = self.time.current_millis();
+
+        critical_section::with(|cs| {
+            let cell = self.state.borrow(cs);
+            let state = cell.get();
+            let current_ts = state.timestamp();
+
+            match now.cmp(&current_ts) {
+                Ordering::Equal => {
+                    if let Some(updated) = self.increment.next(state, &self.rng) {
+                        cell.set(updated);
+                        self.stats.issued();
+                        Poll::Ready { id: updated }
+                    } else {
+                        self.stats.exhausted();
+                        Poll::Pending { yield_for: ID::ONE }
+                    }
+                }
+                Ordering::Greater if now > ID::max_timestamp() => {
+                    Poll::TimestampOverflow { timestamp: now }
+                }
+                Ordering::Greater => {
+                    // Set the new timestamp and random number.
+                    let rand = self.rng.rand();
+                    let updated = state.rollover_to_timestamp(now, rand);
+                    cell.set(updated);
+                    self.stats.issued();
+                    Poll::Ready { id: updated }
+                }
+                Ordering::Less => self.cold_clock_behind(now, current_ts),
+            }
+        })
+    }
+
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`.
+    ///
+    /// See [`BasicMonoUlidGenerator::advance_to`] for details.
+    ///
+    /// [`BasicMonoUlidGenerator::advance_to`]: crate::generator::BasicMonoUlidGenerator::advance_to
+    pub fn advance_to(&self, id: ID) {
+        let target = advance_ulid_target(id, self.node);
+        critical_section::with(|cs| {
+            let cell = self.state.borrow(cs);
+            if target > cell.get() {
+                cell.set(target);
+            }
+        });
+    }
+
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    pub fn last_id(&self) -> ID {
+        critical_section::with(|cs| self.state.borrow(cs).get())
+    }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn cold_clock_behind(&self, now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
+        let yield_for = current_ts - now;
+        debug_assert!(yield_for >= ID::ZERO);
+        self.stats.clock_behind(yield_for);
+        Poll::Pending { yield_for }
+    }
+}
+
+impl<ID, T, R> UlidGenerator<ID, T, R> for CriticalSectionMonoUlidGenerator<ID, T, R>
+where
+    ID: UlidId,
+    T: TimeSource<ID::Ty>,
+    R: RandSource<ID::Ty>,
+{
+    type Err = core::convert::Infallible;
+
+    fn new(time: T, rng: R) -> Self {
+        Self::new(time, rng)
+    }
+
+    fn next_id(&self, f: impl FnMut(ID::Ty)) -> ID {
+        self.next_id(f)
+    }
+
+    fn try_next_id(&self, f: impl FnMut(ID::Ty)) -> Result<ID, Self::Err> {
+        Ok(self.next_id(f))
+    }
+
+    fn poll_id(&self) -> Poll<ID> {
+        self.poll_id()
+    }
+
+    fn try_poll_id(&self) -> Result<Poll<ID>, Self::Err> {
+        Ok(self.poll_id())
+    }
+}
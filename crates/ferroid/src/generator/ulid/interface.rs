@@ -1,7 +1,7 @@
 use core::fmt;
 
 use crate::{
-    generator::{Poll, Result},
+    generator::{Backoff, Poll, Result, timestamp_overflow},
     id::UlidId,
     rand::RandSource,
     time::TimeSource,
@@ -27,6 +27,11 @@ where
     /// - the newly generated ID, or
     /// - a duration to yield/sleep if the generator must wait for the time
     ///   source to advance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator reports [`Poll::TimestampOverflow`], since
+    /// waiting cannot bring the clock back within the layout.
     fn next_id(&self, f: impl FnMut(ID::Ty)) -> ID
     where
         Self::Err: Into<core::convert::Infallible>,
@@ -52,6 +57,11 @@ where
     ///
     /// May return an error if the underlying implementation uses a lock and it
     /// is poisoned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator reports [`Poll::TimestampOverflow`], since
+    /// waiting cannot bring the clock back within the layout.
     fn try_next_id(&self, f: impl FnMut(ID::Ty)) -> Result<ID, Self::Err>;
 
     /// Attempts to generate the next available ID.
@@ -87,4 +97,164 @@ where
     /// May return an error if the underlying implementation uses a lock and it
     /// is poisoned.
     fn try_poll_id(&self) -> Result<Poll<ID>, Self::Err>;
+
+    /// Generates the next available ID, waiting with `backoff` while the
+    /// generator is pending.
+    ///
+    /// This is the infallible counterpart to
+    /// [`UlidGenerator::try_next_id_with_backoff`]. Each `yield_for` is converted into a
+    /// real [`Duration`] using [`TimeSource::units_to_duration`], and the
+    /// backoff is reset once an ID is produced.
+    ///
+    /// [`Duration`]: core::time::Duration
+    fn next_id_with_backoff(&self, backoff: &mut impl Backoff) -> ID
+    where
+        Self::Err: Into<core::convert::Infallible>,
+    {
+        match self.try_next_id_with_backoff(backoff) {
+            Ok(id) => id,
+            Err(e) => {
+                #[allow(unreachable_code)]
+                // `into()` satisfies the trait bound at compile time.
+                match e.into() {}
+            }
+        }
+    }
+
+    /// Generates the next available ID with fallible error handling, waiting
+    /// with `backoff` while the generator is pending.
+    ///
+    /// Each `yield_for` is converted into a real [`Duration`] using
+    /// [`TimeSource::units_to_duration`], and the backoff is reset once an ID
+    /// is produced.
+    ///
+    /// # Errors
+    ///
+    /// May return an error if the underlying implementation uses a lock and it
+    /// is poisoned.
+    ///
+    /// [`Duration`]: core::time::Duration
+    fn try_next_id_with_backoff(&self, backoff: &mut impl Backoff) -> Result<ID, Self::Err> {
+        loop {
+            match self.try_poll_id()? {
+                Poll::Ready { id } => {
+                    backoff.reset();
+                    break Ok(id);
+                }
+                Poll::Pending { yield_for } => backoff.wait(T::units_to_duration(yield_for)),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
+            }
+        }
+    }
+}
+
+/// How much a monotonic ULID generator adds to the random component for each
+/// ID issued within the same tick.
+///
+/// By default the random component is incremented by exactly one, which makes
+/// the IDs that follow a known ID in the same tick trivially guessable. A
+/// random increment draws each step from the generator's [`RandSource`]
+/// instead, at the cost of exhausting the random component sooner.
+#[cfg(any(
+    feature = "basic",
+    feature = "lock",
+    feature = "atomic",
+    feature = "critical-section"
+))]
+pub(crate) enum RandomIncrement<ID: UlidId> {
+    /// Add exactly one.
+    One,
+    /// Add a random amount in `start..start + span`.
+    Range { start: ID::Ty, span: ID::Ty },
+}
+
+#[cfg(any(
+    feature = "basic",
+    feature = "lock",
+    feature = "atomic",
+    feature = "critical-section"
+))]
+impl<ID: UlidId> RandomIncrement<ID> {
+    /// Builds a random increment drawn from `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty or starts at zero, since a zero step would
+    /// repeat the previous ID.
+    pub(crate) fn new(range: core::ops::RangeInclusive<ID::Ty>) -> Self {
+        let (start, end) = range.into_inner();
+        assert!(
+            start > ID::ZERO && start <= end,
+            "random increment range must be non-empty and start above zero"
+        );
+        if end == ID::ONE {
+            Self::One
+        } else {
+            Self::Range {
+                start,
+                span: end - start + ID::ONE,
+            }
+        }
+    }
+
+    /// Returns the next ID within `current`'s tick, or `None` if the random
+    /// component has no room left for the drawn step.
+    #[inline]
+    pub(crate) fn next<R: RandSource<ID::Ty>>(&self, current: ID, rng: &R) -> Option<ID> {
+        match *self {
+            Self::One => current
+                .has_random_room()
+                .then(|| current.increment_random()),
+            Self::Range { start, span } => {
+                let rand = rng.rand();
+                // `Ty` has no `Rem`; the modulo bias is negligible next to the
+                // width of the random component.
+                let step = start + (rand - rand / span * span);
+                (ID::max_random() - current.random() >= step).then(|| {
+                    ID::from_node_components(
+                        current.timestamp(),
+                        current.node(),
+                        current.random() + step,
+                    )
+                })
+            }
+        }
+    }
+}
+
+/// Panics unless `node` fits the node field of `ID`'s layout.
+#[cfg(any(
+    feature = "basic",
+    feature = "lock",
+    feature = "atomic",
+    feature = "critical-section"
+))]
+#[track_caller]
+pub(crate) fn assert_node_fits<ID: UlidId>(node: ID::Ty) {
+    assert!(
+        node <= ID::max_node(),
+        "node {node} does not fit the layout (max {})",
+        ID::max_node()
+    );
+}
+
+/// Returns the state a monotonic generator for `node` must be raised to so
+/// that every ID it issues afterwards is strictly greater than `observed`.
+///
+/// IDs order by timestamp, then node, then random. Within `observed`'s tick, a
+/// lower node can never overtake it, so the random portion is pinned to its
+/// maximum and the generator moves on to the next tick.
+#[cfg(any(
+    feature = "basic",
+    feature = "lock",
+    feature = "atomic",
+    feature = "critical-section"
+))]
+pub(crate) fn advance_ulid_target<ID: UlidId>(observed: ID, node: ID::Ty) -> ID {
+    let random = match observed.node().cmp(&node) {
+        core::cmp::Ordering::Less => ID::ZERO,
+        core::cmp::Ordering::Equal => observed.random(),
+        core::cmp::Ordering::Greater => ID::max_random(),
+    };
+    ID::from_node_components(observed.timestamp(), node, random)
 }
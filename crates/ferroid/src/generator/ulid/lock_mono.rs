@@ -5,7 +5,10 @@ use core::cmp::Ordering;
 use tracing::instrument;
 
 use crate::{
-    generator::{Error, Mutex, Poll, Result, UlidGenerator},
+    generator::{
+        Counters, Error, Mutex, Poll, RandomIncrement, Result, UlidGenerator, advance_ulid_target,
+        assert_node_fits, timestamp_overflow,
+    },
     id::UlidId,
     rand::RandSource,
     time::TimeSource,
@@ -48,6 +51,9 @@ where
     pub(crate) state: Arc<Mutex<ID>>,
     pub(crate) time: T,
     pub(crate) rng: R,
+    node: ID::Ty,
+    increment: RandomIncrement<ID>,
+    stats: Counters,
 }
 
 impl<ID, T, R> LockMonoUlidGenerator<ID, T, R>
@@ -109,7 +115,28 @@ where
     /// In typical use cases, you should prefer [`Self::new`] to let the
     /// generator initialize itself from the current time.
     pub fn from_components(timestamp: ID::Ty, random: ID::Ty, time: T, rng: R) -> Self {
-        let id = ID::from_components(timestamp, random);
+        Self::from_id(ID::from_components(timestamp, random), time, rng)
+    }
+
+    /// Creates a new [`LockMonoUlidGenerator`] that tags every ID with `node`.
+    ///
+    /// See [`BasicMonoUlidGenerator::with_node`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` exceeds [`UlidId::max_node`].
+    ///
+    /// [`BasicMonoUlidGenerator::with_node`]: crate::generator::BasicMonoUlidGenerator::with_node
+    pub fn with_node(node: ID::Ty, time: T, rng: R) -> Self {
+        assert_node_fits::<ID>(node);
+        Self::from_id(
+            ID::from_node_components(ID::ZERO, node, ID::ZERO),
+            time,
+            rng,
+        )
+    }
+
+    fn from_id(id: ID, time: T, rng: R) -> Self {
         Self {
             #[cfg(feature = "cache-padded")]
             state: Arc::new(crossbeam_utils::CachePadded::new(Mutex::new(id))),
@@ -117,9 +144,28 @@ where
             state: Arc::new(Mutex::new(id)),
             time,
             rng,
+            node: id.node(),
+            increment: RandomIncrement::One,
+            stats: Counters::new(),
         }
     }
 
+    /// Increments the random component by a random amount drawn from `range`,
+    /// instead of by one, for IDs issued within the same tick.
+    ///
+    /// See [`BasicMonoUlidGenerator::with_random_increment`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty or starts at zero.
+    ///
+    /// [`BasicMonoUlidGenerator::with_random_increment`]: crate::generator::BasicMonoUlidGenerator::with_random_increment
+    #[must_use]
+    pub fn with_random_increment(mut self, range: core::ops::RangeInclusive<ID::Ty>) -> Self {
+        self.increment = RandomIncrement::new(range);
+        self
+    }
+
     /// Generates a new ULID.
     ///
     /// Returns a new, time-ordered, unique ID.
@@ -179,6 +225,7 @@ where
             match self.try_poll_id()? {
                 Poll::Ready { id } => break Ok(id),
                 Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
             }
         }
     }
@@ -204,6 +251,7 @@ where
     ///     match generator.poll_id() {
     ///         Poll::Ready { id } => break id,
     ///         Poll::Pending { .. } => std::thread::yield_now(),
+    ///         Poll::TimestampOverflow { .. } => panic!("clock overflowed the ID layout"),
     ///     }
     /// };
     /// ```
@@ -240,9 +288,9 @@ where
     /// ```
     /// use ferroid::{
     ///     generator::{LockMonoUlidGenerator, Poll},
-    ///     id::{ToU64, ULID},
+    ///     id::ULID,
     ///     rand::ThreadRandom,
-    ///     time::MonotonicClock,
+    ///     time::{MonotonicClock, TimeSource},
     /// };
     ///
     /// let generator = LockMonoUlidGenerator::new(MonotonicClock::default(), ThreadRandom::default());
@@ -252,12 +300,10 @@ where
     ///     match generator.try_poll_id() {
     ///         Ok(Poll::Ready { id }) => break id,
     ///         Ok(Poll::Pending { yield_for }) => {
-    ///             std::thread::sleep(core::time::Duration::from_millis(
-    ///                 yield_for
-    ///                     .to_u64()
-    ///                     .saturating_mul(MonotonicClock::<1>::GRANULARITY_MILLIS),
-    ///             ));
+    ///             let dur = <MonotonicClock as TimeSource<u128>>::units_to_duration(yield_for);
+    ///             std::thread::sleep(dur);
     ///         }
+    ///         Ok(Poll::TimestampOverflow { .. }) => panic!("clock overflowed the ID layout"),
     ///         Err(e) => panic!("Generator error: {}", e),
     ///     }
     /// };
@@ -285,27 +331,125 @@ where
 
         match now.cmp(&current_ts) {
             Ordering::Equal => {
-                if id.has_random_room() {
-                    *id = id.increment_random();
+                if let Some(updated) = self.increment.next(*id, &self.rng) {
+                    *id = updated;
+                    self.stats.issued();
                     Ok(Poll::Ready { id: *id })
                 } else {
+                    self.stats.exhausted();
                     Ok(Poll::Pending { yield_for: ID::ONE })
                 }
             }
+            Ordering::Greater if now > ID::max_timestamp() => {
+                Ok(Poll::TimestampOverflow { timestamp: now })
+            }
             Ordering::Greater => {
                 let rand = self.rng.rand();
                 *id = id.rollover_to_timestamp(now, rand);
+                self.stats.issued();
                 Ok(Poll::Ready { id: *id })
             }
-            Ordering::Less => Ok(Self::cold_clock_behind(now, current_ts)),
+            Ordering::Less => Ok(self.cold_clock_behind(now, current_ts)),
         }
     }
 
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`.
+    ///
+    /// See [`BasicMonoUlidGenerator::advance_to`] for details.
+    ///
+    /// [`BasicMonoUlidGenerator::advance_to`]: crate::generator::BasicMonoUlidGenerator::advance_to
+    #[cfg(feature = "parking-lot")]
+    pub fn advance_to(&self, id: ID)
+    where
+        Error: Into<core::convert::Infallible>,
+    {
+        if let Err(e) = self.try_advance_to(id) {
+            #[allow(unreachable_code)]
+            // `into()` satisfies the trait bound at compile time.
+            match Into::<core::convert::Infallible>::into(e) {}
+        }
+    }
+
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`, with fallible error handling.
+    ///
+    /// See [`BasicMonoUlidGenerator::advance_to`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock is poisoned.
+    ///
+    /// [`BasicMonoUlidGenerator::advance_to`]: crate::generator::BasicMonoUlidGenerator::advance_to
+    pub fn try_advance_to(&self, id: ID) -> Result<(), Error> {
+        let mut current = {
+            #[cfg(feature = "parking-lot")]
+            {
+                self.state.lock()
+            }
+            #[cfg(not(feature = "parking-lot"))]
+            {
+                self.state.lock()?
+            }
+        };
+        let target = advance_ulid_target(id, self.node);
+        if target > *current {
+            *current = target;
+        }
+        Ok(())
+    }
+
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    #[cfg(feature = "parking-lot")]
+    pub fn last_id(&self) -> ID
+    where
+        Error: Into<core::convert::Infallible>,
+    {
+        match self.try_last_id() {
+            Ok(id) => id,
+            Err(e) => {
+                #[allow(unreachable_code)]
+                // `into()` satisfies the trait bound at compile time.
+                match Into::<core::convert::Infallible>::into(e) {}
+            }
+        }
+    }
+
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock is poisoned.
+    pub fn try_last_id(&self) -> Result<ID, Error> {
+        #[cfg(feature = "parking-lot")]
+        {
+            Ok(*self.state.lock())
+        }
+        #[cfg(not(feature = "parking-lot"))]
+        {
+            Ok(*self.state.lock()?)
+        }
+    }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
+
     #[cold]
     #[inline(never)]
-    fn cold_clock_behind(now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
+    fn cold_clock_behind(&self, now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
         let yield_for = current_ts - now;
         debug_assert!(yield_for >= ID::ZERO);
+        self.stats.clock_behind(yield_for);
         Poll::Pending { yield_for }
     }
 }
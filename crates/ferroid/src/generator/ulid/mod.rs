@@ -4,9 +4,13 @@ mod atomic_mono;
 mod basic;
 #[cfg(feature = "basic")]
 mod basic_mono;
+#[cfg(feature = "critical-section")]
+mod critical_section_mono;
 mod interface;
 #[cfg(feature = "lock")]
 mod lock_mono;
+#[cfg(all(feature = "atomic", target_has_atomic = "32"))]
+mod seqlock_mono;
 #[cfg(all(
     test,
     feature = "std",
@@ -17,10 +21,6 @@ mod lock_mono;
 ))]
 mod tests;
 
-#[cfg_attr(docsrs, doc(cfg(all(feature = "ulid", feature = "thread-local"))))]
-#[cfg(feature = "thread-local")]
-pub mod thread_local;
-
 #[cfg_attr(
     docsrs,
     doc(cfg(all(feature = "ulid", feature = "atomic", target_has_atomic = "128")))
@@ -33,8 +33,17 @@ pub use basic::*;
 #[cfg_attr(docsrs, doc(cfg(all(feature = "ulid", feature = "basic"))))]
 #[cfg(feature = "basic")]
 pub use basic_mono::*;
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ulid", feature = "critical-section"))))]
+#[cfg(feature = "critical-section")]
+pub use critical_section_mono::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "ulid")))]
 pub use interface::*;
 #[cfg_attr(docsrs, doc(cfg(all(feature = "ulid", feature = "lock"))))]
 #[cfg(feature = "lock")]
 pub use lock_mono::*;
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "ulid", feature = "atomic", target_has_atomic = "32")))
+)]
+#[cfg(all(feature = "atomic", target_has_atomic = "32"))]
+pub use seqlock_mono::*;
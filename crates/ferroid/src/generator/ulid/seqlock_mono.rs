@@ -0,0 +1,452 @@
+use core::{cmp, marker::PhantomData};
+
+use portable_atomic::{AtomicU32, Ordering, fence};
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+use crate::{
+    generator::{
+        Counters, Poll, RandomIncrement, Result, UlidGenerator, advance_ulid_target,
+        assert_node_fits, timestamp_overflow,
+    },
+    id::UlidId,
+    rand::RandSource,
+    time::TimeSource,
+};
+
+/// A *monotonic* ULID-style ID generator for multi-threaded environments on
+/// targets without 128-bit atomics.
+///
+/// The 128-bit state is split across four [`AtomicU32`] words guarded by a
+/// sequence counter (a seqlock). Callers read a snapshot optimistically,
+/// compute the next ID without holding anything, and then publish it by
+/// claiming the sequence counter with a single compare-and-swap. Only 32-bit
+/// atomics are required, so this works on 32-bit ARM, riscv32, and other
+/// `no_std` targets where [`AtomicMonoUlidGenerator`] is unavailable.
+///
+/// ## Features
+/// - ✅ Thread-safe
+/// - ✅ Probabilistically unique (no coordination required)
+/// - ✅ Time-ordered (monotonically increasing per time-source tick)
+/// - ✅ Requires only 32-bit atomics and no `std`
+///
+/// ## Caveats
+/// This generator is **not strictly lock-free**. Publishing a new state takes
+/// four word stores between claiming and releasing the sequence counter. If the
+/// publishing thread is preempted inside that window, every other caller
+/// observes an in-progress write and receives [`Poll::Pending`] with a
+/// `yield_for` of zero until the publisher resumes. The window is a handful of
+/// instructions, so this is rare in practice, but it means a stalled thread can
+/// delay (never corrupt) progress for others. On targets with 128-bit atomics,
+/// prefer [`AtomicMonoUlidGenerator`].
+///
+/// The sequence counter is 32 bits wide. A caller that is suspended between
+/// reading a snapshot and publishing while exactly 2<sup>31</sup> other
+/// publications complete could mistake its stale snapshot for the current
+/// state.
+///
+/// Only layouts whose underlying type is [`u128`] are supported.
+///
+/// ## Recommended When
+/// - You're in a multi-threaded environment without 128-bit atomics
+/// - You need monotonically increasing IDs (IDs generated within the same
+///   time-source tick increment the random component)
+///
+/// ## See Also
+/// - [`BasicMonoUlidGenerator`]
+/// - [`LockMonoUlidGenerator`]
+/// - [`AtomicMonoUlidGenerator`]
+///
+/// [`BasicMonoUlidGenerator`]: crate::generator::BasicMonoUlidGenerator
+/// [`LockMonoUlidGenerator`]: crate::generator::LockMonoUlidGenerator
+/// [`AtomicMonoUlidGenerator`]: crate::generator::AtomicMonoUlidGenerator
+pub struct SeqLockMonoUlidGenerator<ID, T, R>
+where
+    ID: UlidId<Ty = u128>,
+    T: TimeSource<ID::Ty>,
+    R: RandSource<ID::Ty>,
+{
+    #[cfg(feature = "cache-padded")]
+    state: crossbeam_utils::CachePadded<SeqLockState>,
+    #[cfg(not(feature = "cache-padded"))]
+    state: SeqLockState,
+    time: T,
+    rng: R,
+    node: ID::Ty,
+    increment: RandomIncrement<ID>,
+    stats: Counters,
+    _id: PhantomData<ID>,
+}
+
+/// The seqlock-protected state. `seq` is even while the state is stable and
+/// odd while a publication is in progress.
+struct SeqLockState {
+    seq: AtomicU32,
+    /// The raw ID, most significant word first.
+    words: [AtomicU32; 4],
+}
+
+impl SeqLockState {
+    fn new(raw: u128) -> Self {
+        Self {
+            seq: AtomicU32::new(0),
+            words: split(raw).map(AtomicU32::new),
+        }
+    }
+}
+
+impl<ID, T, R> SeqLockMonoUlidGenerator<ID, T, R>
+where
+    ID: UlidId<Ty = u128>,
+    T: TimeSource<ID::Ty>,
+    R: RandSource<ID::Ty>,
+{
+    /// Creates a new [`SeqLockMonoUlidGenerator`] with the provided time source
+    /// and RNG.
+    ///
+    /// # Parameters
+    /// - `time`: A [`TimeSource`] used to retrieve the current timestamp
+    /// - `rng`: A [`RandSource`] used to generate random bits
+    ///
+    /// # Returns
+    /// A ready-to-use ULID generator suitable for producing unique, sortable
+    /// IDs.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::{Poll, SeqLockMonoUlidGenerator},
+    ///     id::ULID,
+    ///     rand::ThreadRandom,
+    ///     time::MonotonicClock,
+    /// };
+    ///
+    /// let generator =
+    ///     SeqLockMonoUlidGenerator::new(MonotonicClock::default(), ThreadRandom::default());
+    ///
+    /// let id: ULID = generator.next_id(|_| std::thread::yield_now());
+    /// ```
+    ///
+    /// [`TimeSource`]: crate::time::TimeSource
+    /// [`RandSource`]: crate::rand::RandSource
+    pub fn new(time: T, rng: R) -> Self {
+        Self::from_components(ID::ZERO, ID::ZERO, time, rng)
+    }
+
+    /// Creates a new ID generator from explicit component values.
+    ///
+    /// This constructor is primarily useful for advanced use cases such as
+    /// restoring state from persistent storage or controlling the starting
+    /// point of the generator manually.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The initial timestamp component (usually in
+    ///   time-source units)
+    /// - `random`: The initial random component
+    /// - `time`: A [`TimeSource`] implementation used to fetch the current time
+    /// - `rng`: A [`RandSource`] used to generate future random bits
+    ///
+    /// # Returns
+    /// A new generator instance preloaded with the given state.
+    ///
+    /// # ⚠️ Note
+    /// In typical use cases, you should prefer [`Self::new`] to let the
+    /// generator initialize itself from the current time.
+    pub fn from_components(timestamp: ID::Ty, random: ID::Ty, time: T, rng: R) -> Self {
+        Self::from_id(ID::from_components(timestamp, random), time, rng)
+    }
+
+    /// Creates a new [`SeqLockMonoUlidGenerator`] that tags every ID with
+    /// `node`.
+    ///
+    /// See [`BasicMonoUlidGenerator::with_node`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` exceeds [`UlidId::max_node`].
+    ///
+    /// [`BasicMonoUlidGenerator::with_node`]: crate::generator::BasicMonoUlidGenerator::with_node
+    pub fn with_node(node: ID::Ty, time: T, rng: R) -> Self {
+        assert_node_fits::<ID>(node);
+        Self::from_id(
+            ID::from_node_components(ID::ZERO, node, ID::ZERO),
+            time,
+            rng,
+        )
+    }
+
+    fn from_id(id: ID, time: T, rng: R) -> Self {
+        Self {
+            #[cfg(feature = "cache-padded")]
+            state: crossbeam_utils::CachePadded::new(SeqLockState::new(id.to_raw())),
+            #[cfg(not(feature = "cache-padded"))]
+            state: SeqLockState::new(id.to_raw()),
+            time,
+            rng,
+            node: id.node(),
+            increment: RandomIncrement::One,
+            stats: Counters::new(),
+            _id: PhantomData,
+        }
+    }
+
+    /// Increments the random component by a random amount drawn from `range`,
+    /// instead of by one, for IDs issued within the same tick.
+    ///
+    /// See [`BasicMonoUlidGenerator::with_random_increment`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty or starts at zero.
+    ///
+    /// [`BasicMonoUlidGenerator::with_random_increment`]: crate::generator::BasicMonoUlidGenerator::with_random_increment
+    #[must_use]
+    pub fn with_random_increment(mut self, range: core::ops::RangeInclusive<ID::Ty>) -> Self {
+        self.increment = RandomIncrement::new(range);
+        self
+    }
+
+    /// Generates a new ULID.
+    ///
+    /// Returns a new, time-ordered, unique ID.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::{Poll, SeqLockMonoUlidGenerator},
+    ///     id::ULID,
+    ///     rand::ThreadRandom,
+    ///     time::MonotonicClock,
+    /// };
+    ///
+    /// let generator =
+    ///     SeqLockMonoUlidGenerator::new(MonotonicClock::default(), ThreadRandom::default());
+    ///
+    /// let id: ULID = generator.next_id(|_| std::thread::yield_now());
+    /// ```
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self, f)))]
+    pub fn next_id(&self, mut f: impl FnMut(ID::Ty)) -> ID {
+        loop {
+            match self.poll_id() {
+                Poll::Ready { id } => break id,
+                Poll::Pending { yield_for } => f(yield_for),
+                Poll::TimestampOverflow { timestamp } => timestamp_overflow(timestamp),
+            }
+        }
+    }
+
+    /// Attempts to generate a new ULID.
+    ///
+    /// Returns a new, time-ordered, unique ID if generation succeeds. If the
+    /// generator is temporarily exhausted (e.g., the random component is
+    /// exhausted and the time has not advanced), it returns [`Poll::Pending`].
+    /// If another thread is publishing concurrently or won the race, it returns
+    /// [`Poll::Pending`] with a `yield_for` of zero to signal an immediate
+    /// retry.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::{
+    ///     generator::{Poll, SeqLockMonoUlidGenerator},
+    ///     id::ULID,
+    ///     rand::ThreadRandom,
+    ///     time::MonotonicClock,
+    /// };
+    ///
+    /// let generator =
+    ///     SeqLockMonoUlidGenerator::new(MonotonicClock::default(), ThreadRandom::default());
+    ///
+    /// let id: ULID = loop {
+    ///     match generator.poll_id() {
+    ///         Poll::Ready { id } => break id,
+    ///         Poll::Pending { .. } => std::thread::yield_now(),
+    ///         Poll::TimestampOverflow { .. } => panic!("clock overflowed the ID layout"),
+    ///     }
+    /// };
+    /// ```
+    #[cfg_attr(feature = "tracing", instrument(level = "trace", skip(self)))]
+    pub fn poll_id(&self) -> Poll<ID> {
+        let now = self.time.current_millis();
+
+        let Some((seq, current_raw)) = self.read() else {
+            return self.contended();
+        };
+        let current_id = ID::from_raw(current_raw);
+        let current_ts = current_id.timestamp();
+
+        let next_id = match now.cmp(&current_ts) {
+            cmp::Ordering::Equal => {
+                if let Some(next_id) = self.increment.next(current_id, &self.rng) {
+                    next_id
+                } else {
+                    self.stats.exhausted();
+                    return Poll::Pending { yield_for: ID::ONE };
+                }
+            }
+            cmp::Ordering::Greater if now > ID::max_timestamp() => {
+                return Poll::TimestampOverflow { timestamp: now };
+            }
+            cmp::Ordering::Greater => current_id.rollover_to_timestamp(now, self.rng.rand()),
+            cmp::Ordering::Less => {
+                return self.cold_clock_behind(now, current_ts);
+            }
+        };
+
+        if self.publish(seq, next_id.to_raw()) {
+            self.stats.issued();
+            Poll::Ready { id: next_id }
+        } else {
+            self.contended()
+        }
+    }
+
+    /// Takes a consistent snapshot of the state, returning the sequence number
+    /// it was taken at, or `None` if a publication was in progress.
+    #[inline]
+    fn read(&self) -> Option<(u32, u128)> {
+        let seq = self.state.seq.load(Ordering::Acquire);
+        if seq & 1 == 1 {
+            return None;
+        }
+        let words = self
+            .state
+            .words
+            .each_ref()
+            .map(|word| word.load(Ordering::Relaxed));
+        fence(Ordering::Acquire);
+        if self.state.seq.load(Ordering::Relaxed) != seq {
+            return None;
+        }
+        Some((seq, join(words)))
+    }
+
+    /// Publishes `raw` if the state has not changed since the snapshot taken at
+    /// `seq`.
+    #[inline]
+    fn publish(&self, seq: u32, raw: u128) -> bool {
+        if self
+            .state
+            .seq
+            .compare_exchange(
+                seq,
+                seq.wrapping_add(1),
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            )
+            .is_err()
+        {
+            return false;
+        }
+        fence(Ordering::Release);
+        for (word, value) in self.state.words.iter().zip(split(raw)) {
+            word.store(value, Ordering::Relaxed);
+        }
+        self.state.seq.store(seq.wrapping_add(2), Ordering::Release);
+        true
+    }
+
+    /// Another thread is publishing or won the race. Yield 0 to retry
+    /// immediately.
+    #[inline]
+    fn contended(&self) -> Poll<ID> {
+        self.stats.contention();
+        Poll::Pending {
+            yield_for: ID::ZERO,
+        }
+    }
+
+    /// Raises the generator's state so that every ID issued afterwards is
+    /// strictly greater than `id`.
+    ///
+    /// See [`BasicMonoUlidGenerator::advance_to`] for details.
+    ///
+    /// [`BasicMonoUlidGenerator::advance_to`]: crate::generator::BasicMonoUlidGenerator::advance_to
+    pub fn advance_to(&self, id: ID) {
+        let target = advance_ulid_target(id, self.node).to_raw();
+        loop {
+            match self.read() {
+                Some((_, current)) if current >= target => return,
+                Some((seq, _)) if self.publish(seq, target) => return,
+                _ => core::hint::spin_loop(),
+            }
+        }
+    }
+
+    /// Returns the most recently issued ID, or the initial state if no ID has
+    /// been issued yet.
+    pub fn last_id(&self) -> ID {
+        loop {
+            if let Some((_, raw)) = self.read() {
+                break ID::from_raw(raw);
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Returns a snapshot of this generator's counters.
+    ///
+    /// See [`GeneratorStats`] for details.
+    ///
+    /// [`GeneratorStats`]: crate::generator::GeneratorStats
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::generator::GeneratorStats {
+        self.stats.snapshot()
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn cold_clock_behind(&self, now: ID::Ty, current_ts: ID::Ty) -> Poll<ID> {
+        let yield_for = current_ts - now;
+        debug_assert!(yield_for >= ID::ZERO);
+        self.stats.clock_behind(yield_for);
+        Poll::Pending { yield_for }
+    }
+}
+
+impl<ID, T, R> UlidGenerator<ID, T, R> for SeqLockMonoUlidGenerator<ID, T, R>
+where
+    ID: UlidId<Ty = u128>,
+    T: TimeSource<u128>,
+    R: RandSource<u128>,
+{
+    type Err = core::convert::Infallible;
+
+    fn new(time: T, rng: R) -> Self {
+        Self::new(time, rng)
+    }
+
+    fn next_id(&self, f: impl FnMut(ID::Ty)) -> ID {
+        self.next_id(f)
+    }
+
+    fn try_next_id(&self, f: impl FnMut(ID::Ty)) -> Result<ID, Self::Err> {
+        Ok(self.next_id(f))
+    }
+
+    fn poll_id(&self) -> Poll<ID> {
+        self.poll_id()
+    }
+
+    fn try_poll_id(&self) -> Result<Poll<ID>, Self::Err> {
+        Ok(self.poll_id())
+    }
+}
+
+/// Splits `raw` into 32-bit words, most significant first.
+#[allow(clippy::cast_possible_truncation)]
+const fn split(raw: u128) -> [u32; 4] {
+    [
+        (raw >> 96) as u32,
+        (raw >> 64) as u32,
+        (raw >> 32) as u32,
+        raw as u32,
+    ]
+}
+
+/// The inverse of [`split`].
+fn join(words: [u32; 4]) -> u128 {
+    words
+        .into_iter()
+        .fold(0, |acc, word| (acc << 32) | u128::from(word))
+}
@@ -1,5 +1,5 @@
 use alloc::{rc::Rc, sync::Arc, vec, vec::Vec};
-use core::cell::Cell;
+use core::{cell::Cell, time::Duration};
 use std::{
     collections::HashSet,
     sync::Mutex,
@@ -8,13 +8,30 @@ use std::{
 
 use crate::{
     generator::{
-        BasicMonoUlidGenerator, BasicUlidGenerator, LockMonoUlidGenerator, Poll, UlidGenerator,
+        BasicMonoUlidGenerator, BasicUlidGenerator, LockMonoUlidGenerator, Poll,
+        SeqLockMonoUlidGenerator, UlidGenerator,
     },
     id::{Id, ToU64, ULID, UlidId},
     rand::{RandSource, ThreadRandom},
     time::{MonotonicClock, TimeSource, UNIX_EPOCH},
 };
 
+// Local `define_ulid!` expansions are linted as if hand-written.
+#[allow(clippy::wrong_self_convention)]
+mod layouts {
+    use crate::define_ulid;
+
+    define_ulid!(
+        /// A ULID with a 10-bit node field.
+        NodeUlid, u128,
+        reserved: 0,
+        timestamp: 48,
+        node: 10,
+        random: 70
+    );
+}
+use layouts::NodeUlid;
+
 struct MockTime {
     millis: u128,
 }
@@ -87,6 +104,7 @@ where
 {
     fn unwrap_ready(self) -> T;
     fn unwrap_pending(self) -> T::Ty;
+    fn unwrap_overflow(self) -> T::Ty;
 }
 
 impl<T> IdGenStatusExt<T> for Poll<T>
@@ -99,6 +117,9 @@ where
             Self::Pending { yield_for } => {
                 panic!("unexpected pending (yield for: {yield_for})")
             }
+            Self::TimestampOverflow { timestamp } => {
+                panic!("unexpected overflow (timestamp: {timestamp})")
+            }
         }
     }
 
@@ -106,6 +127,19 @@ where
         match self {
             Self::Ready { id } => panic!("unexpected ready ({id:?})"),
             Self::Pending { yield_for } => yield_for,
+            Self::TimestampOverflow { timestamp } => {
+                panic!("unexpected overflow (timestamp: {timestamp})")
+            }
+        }
+    }
+
+    fn unwrap_overflow(self) -> T::Ty {
+        match self {
+            Self::Ready { id } => panic!("unexpected ready ({id:?})"),
+            Self::Pending { yield_for } => {
+                panic!("unexpected pending (yield for: {yield_for})")
+            }
+            Self::TimestampOverflow { timestamp } => timestamp,
         }
     }
 }
@@ -141,6 +175,18 @@ where
     assert_eq!(yield_for, ID::ONE);
 }
 
+fn run_generator_reports_timestamp_overflow<G, ID, T, R>(generator: &G)
+where
+    G: UlidGenerator<ID, T, R>,
+    ID: UlidId,
+    T: TimeSource<ID::Ty>,
+    R: RandSource<ID::Ty>,
+{
+    let timestamp = generator.try_poll_id().unwrap().unwrap_overflow();
+    assert_eq!(timestamp, ID::max_timestamp() + ID::ONE);
+    generator.try_poll_id().unwrap().unwrap_overflow();
+}
+
 fn run_generator_handles_rollover<G, ID, T, R>(generator: &G, shared_time: &SharedMockStepTime)
 where
     G: UlidGenerator<ID, T, R>,
@@ -191,6 +237,9 @@ where
                 Poll::Pending { .. } => {
                     core::hint::spin_loop();
                 }
+                Poll::TimestampOverflow { timestamp } => {
+                    panic!("unexpected overflow ({timestamp})")
+                }
             }
         }
     }
@@ -224,6 +273,9 @@ where
                                 break;
                             }
                             Poll::Pending { .. } => std::thread::yield_now(),
+                            Poll::TimestampOverflow { timestamp } => {
+                                panic!("unexpected overflow ({timestamp})")
+                            }
                         }
                     }
                 }
@@ -277,6 +329,29 @@ fn atomic_generator_mono_sequence_test() {
     run_id_sequence_increments_within_same_tick(&generator);
 }
 
+#[test]
+fn seqlock_generator_mono_sequence_test() {
+    let mock_time = MockTime { millis: 42 };
+    let mock_rand = MockRand { rand: 42 };
+
+    let generator: SeqLockMonoUlidGenerator<ULID, _, _> =
+        SeqLockMonoUlidGenerator::new(mock_time, mock_rand);
+    run_id_sequence_increments_within_same_tick(&generator);
+}
+
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_mono_sequence_test() {
+    use crate::generator::CriticalSectionMonoUlidGenerator;
+
+    let mock_time = MockTime { millis: 42 };
+    let mock_rand = MockRand { rand: 42 };
+
+    let generator: CriticalSectionMonoUlidGenerator<ULID, _, _> =
+        CriticalSectionMonoUlidGenerator::new(mock_time, mock_rand);
+    run_id_sequence_increments_within_same_tick(&generator);
+}
+
 #[test]
 fn basic_generator_mono_pending_test() {
     let generator: BasicMonoUlidGenerator<ULID, _, _> =
@@ -301,6 +376,445 @@ fn atomic_generator_mono_pending_test() {
     run_generator_returns_pending_when_sequence_exhausted(&generator);
 }
 
+#[test]
+fn seqlock_generator_mono_pending_test() {
+    let generator: SeqLockMonoUlidGenerator<ULID, _, _> =
+        SeqLockMonoUlidGenerator::from_components(0, ULID::max_random(), FixedTime, MinRand);
+    run_generator_returns_pending_when_sequence_exhausted(&generator);
+}
+
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_mono_pending_test() {
+    use crate::generator::CriticalSectionMonoUlidGenerator;
+
+    let generator: CriticalSectionMonoUlidGenerator<ULID, _, _> =
+        CriticalSectionMonoUlidGenerator::from_components(
+            0,
+            ULID::max_random(),
+            FixedTime,
+            MinRand,
+        );
+    run_generator_returns_pending_when_sequence_exhausted(&generator);
+}
+
+#[test]
+fn basic_generator_timestamp_overflow_test() {
+    let mock_time = MockTime {
+        millis: ULID::max_timestamp() + 1,
+    };
+    let generator: BasicUlidGenerator<ULID, _, _> = BasicUlidGenerator::new(mock_time, MinRand);
+    run_generator_reports_timestamp_overflow(&generator);
+}
+
+#[test]
+fn basic_generator_mono_timestamp_overflow_test() {
+    let mock_time = MockTime {
+        millis: ULID::max_timestamp() + 1,
+    };
+    let generator: BasicMonoUlidGenerator<ULID, _, _> =
+        BasicMonoUlidGenerator::new(mock_time, MinRand);
+    run_generator_reports_timestamp_overflow(&generator);
+}
+
+#[test]
+fn lock_generator_mono_timestamp_overflow_test() {
+    let mock_time = MockTime {
+        millis: ULID::max_timestamp() + 1,
+    };
+    let generator: LockMonoUlidGenerator<ULID, _, _> =
+        LockMonoUlidGenerator::new(mock_time, MinRand);
+    run_generator_reports_timestamp_overflow(&generator);
+}
+
+#[test]
+#[cfg(target_has_atomic = "128")]
+fn atomic_generator_mono_timestamp_overflow_test() {
+    use crate::generator::AtomicMonoUlidGenerator;
+
+    let mock_time = MockTime {
+        millis: ULID::max_timestamp() + 1,
+    };
+    let generator: AtomicMonoUlidGenerator<ULID, _, _> =
+        AtomicMonoUlidGenerator::new(mock_time, MinRand);
+    run_generator_reports_timestamp_overflow(&generator);
+}
+
+#[test]
+fn seqlock_generator_mono_timestamp_overflow_test() {
+    let mock_time = MockTime {
+        millis: ULID::max_timestamp() + 1,
+    };
+    let generator: SeqLockMonoUlidGenerator<ULID, _, _> =
+        SeqLockMonoUlidGenerator::new(mock_time, MinRand);
+    run_generator_reports_timestamp_overflow(&generator);
+}
+
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_mono_timestamp_overflow_test() {
+    use crate::generator::CriticalSectionMonoUlidGenerator;
+
+    let mock_time = MockTime {
+        millis: ULID::max_timestamp() + 1,
+    };
+    let generator: CriticalSectionMonoUlidGenerator<ULID, _, _> =
+        CriticalSectionMonoUlidGenerator::new(mock_time, MinRand);
+    run_generator_reports_timestamp_overflow(&generator);
+}
+
+#[test]
+fn ulid_lifetime_outlasts_u64_nanoseconds() {
+    let max = ULID::max_timestamp();
+    assert_eq!(
+        ULID::remaining_lifetime(&MockTime { millis: 0 }),
+        Duration::from_millis(u64::try_from(max).unwrap() + 1)
+    );
+    assert_eq!(
+        ULID::remaining_lifetime(&MockTime { millis: max + 1 }),
+        Duration::ZERO
+    );
+    // 10889-08-02T05:31:50.656Z
+    let date = ULID::exhaustion_date::<MockTime>(UNIX_EPOCH);
+    assert_eq!(date.as_millis(), 1 << 48);
+}
+
+fn assert_advances_past_observed_id(
+    advance_to: impl Fn(ULID),
+    last_id: impl Fn() -> ULID,
+    poll_id: impl Fn() -> Poll<ULID>,
+) {
+    let observed = ULID::from_components(100, 42);
+    advance_to(observed);
+    assert_eq!(last_id(), observed);
+    assert_eq!(poll_id().unwrap_ready(), ULID::from_components(100, 43));
+
+    // Older IDs never move the state backwards.
+    advance_to(ULID::from_components(99, ULID::max_random()));
+    assert_eq!(last_id(), ULID::from_components(100, 43));
+
+    // An exhausted tick waits for the clock.
+    advance_to(ULID::from_components(100, ULID::max_random()));
+    assert_eq!(poll_id().unwrap_pending(), 1);
+}
+
+#[test]
+fn basic_generator_mono_advance_to_test() {
+    let generator: BasicMonoUlidGenerator<ULID, _, _> =
+        BasicMonoUlidGenerator::new(MockTime { millis: 100 }, MinRand);
+    assert_advances_past_observed_id(
+        |id| generator.advance_to(id),
+        || generator.last_id(),
+        || generator.poll_id(),
+    );
+}
+
+#[test]
+fn lock_generator_mono_advance_to_test() {
+    let generator: LockMonoUlidGenerator<ULID, _, _> =
+        LockMonoUlidGenerator::new(MockTime { millis: 100 }, MinRand);
+    assert_advances_past_observed_id(
+        |id| generator.try_advance_to(id).unwrap(),
+        || generator.try_last_id().unwrap(),
+        || generator.try_poll_id().unwrap(),
+    );
+}
+
+#[test]
+#[cfg(target_has_atomic = "128")]
+fn atomic_generator_mono_advance_to_test() {
+    use crate::generator::AtomicMonoUlidGenerator;
+
+    let generator: AtomicMonoUlidGenerator<ULID, _, _> =
+        AtomicMonoUlidGenerator::new(MockTime { millis: 100 }, MinRand);
+    assert_advances_past_observed_id(
+        |id| generator.advance_to(id),
+        || generator.last_id(),
+        || generator.poll_id(),
+    );
+}
+
+#[test]
+fn seqlock_generator_mono_advance_to_test() {
+    let generator: SeqLockMonoUlidGenerator<ULID, _, _> =
+        SeqLockMonoUlidGenerator::new(MockTime { millis: 100 }, MinRand);
+    assert_advances_past_observed_id(
+        |id| generator.advance_to(id),
+        || generator.last_id(),
+        || generator.poll_id(),
+    );
+}
+
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_mono_advance_to_test() {
+    use crate::generator::CriticalSectionMonoUlidGenerator;
+
+    let generator: CriticalSectionMonoUlidGenerator<ULID, _, _> =
+        CriticalSectionMonoUlidGenerator::new(MockTime { millis: 100 }, MinRand);
+    assert_advances_past_observed_id(
+        |id| generator.advance_to(id),
+        || generator.last_id(),
+        || generator.poll_id(),
+    );
+}
+
+struct CountingRand {
+    next: Cell<u128>,
+}
+
+impl CountingRand {
+    fn new() -> Self {
+        Self { next: Cell::new(0) }
+    }
+}
+
+impl RandSource<u128> for CountingRand {
+    fn rand(&self) -> u128 {
+        let rand = self.next.get();
+        self.next.set(rand + 1);
+        rand
+    }
+}
+
+fn run_random_increment_steps_within_range(poll_id: impl Fn() -> Poll<ULID>) {
+    // The tick opens with the RNG's first draw (0) as the random component.
+    let mut previous = poll_id().unwrap_ready();
+    assert_eq!(previous, ULID::from_components(1, 0));
+
+    for draw in 1..64 {
+        let id = poll_id().unwrap_ready();
+        assert_eq!(id.timestamp(), 1);
+        assert_eq!(id.random() - previous.random(), 1 + draw % 16);
+        previous = id;
+    }
+}
+
+fn run_random_increment_waits_when_step_does_not_fit(
+    poll_id: impl Fn() -> Poll<ULID>,
+    last_id: impl Fn() -> ULID,
+) {
+    // `MaxRand` always draws the largest step (8), which needs more room than
+    // the 3 values left in the random component.
+    let before = last_id();
+    assert_eq!(poll_id().unwrap_pending(), 1);
+    assert_eq!(last_id(), before);
+}
+
+#[test]
+fn basic_generator_mono_random_increment_test() {
+    let generator: BasicMonoUlidGenerator<ULID, _, _> =
+        BasicMonoUlidGenerator::new(MockTime { millis: 1 }, CountingRand::new())
+            .with_random_increment(1..=16);
+    run_random_increment_steps_within_range(|| generator.poll_id());
+
+    let generator: BasicMonoUlidGenerator<ULID, _, _> =
+        BasicMonoUlidGenerator::from_components(0, ULID::max_random() - 3, FixedTime, MaxRand)
+            .with_random_increment(1..=8);
+    run_random_increment_waits_when_step_does_not_fit(
+        || generator.poll_id(),
+        || generator.last_id(),
+    );
+}
+
+#[test]
+fn lock_generator_mono_random_increment_test() {
+    let generator: LockMonoUlidGenerator<ULID, _, _> =
+        LockMonoUlidGenerator::new(MockTime { millis: 1 }, CountingRand::new())
+            .with_random_increment(1..=16);
+    run_random_increment_steps_within_range(|| generator.try_poll_id().unwrap());
+
+    let generator: LockMonoUlidGenerator<ULID, _, _> =
+        LockMonoUlidGenerator::from_components(0, ULID::max_random() - 3, FixedTime, MaxRand)
+            .with_random_increment(1..=8);
+    run_random_increment_waits_when_step_does_not_fit(
+        || generator.try_poll_id().unwrap(),
+        || generator.try_last_id().unwrap(),
+    );
+}
+
+#[test]
+#[cfg(target_has_atomic = "128")]
+fn atomic_generator_mono_random_increment_test() {
+    use crate::generator::AtomicMonoUlidGenerator;
+
+    let generator: AtomicMonoUlidGenerator<ULID, _, _> =
+        AtomicMonoUlidGenerator::new(MockTime { millis: 1 }, CountingRand::new())
+            .with_random_increment(1..=16);
+    run_random_increment_steps_within_range(|| generator.poll_id());
+
+    let generator: AtomicMonoUlidGenerator<ULID, _, _> =
+        AtomicMonoUlidGenerator::from_components(0, ULID::max_random() - 3, FixedTime, MaxRand)
+            .with_random_increment(1..=8);
+    run_random_increment_waits_when_step_does_not_fit(
+        || generator.poll_id(),
+        || generator.last_id(),
+    );
+}
+
+#[test]
+fn seqlock_generator_mono_random_increment_test() {
+    let generator: SeqLockMonoUlidGenerator<ULID, _, _> =
+        SeqLockMonoUlidGenerator::new(MockTime { millis: 1 }, CountingRand::new())
+            .with_random_increment(1..=16);
+    run_random_increment_steps_within_range(|| generator.poll_id());
+
+    let generator: SeqLockMonoUlidGenerator<ULID, _, _> =
+        SeqLockMonoUlidGenerator::from_components(0, ULID::max_random() - 3, FixedTime, MaxRand)
+            .with_random_increment(1..=8);
+    run_random_increment_waits_when_step_does_not_fit(
+        || generator.poll_id(),
+        || generator.last_id(),
+    );
+}
+
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_mono_random_increment_test() {
+    use crate::generator::CriticalSectionMonoUlidGenerator;
+
+    let generator: CriticalSectionMonoUlidGenerator<ULID, _, _> =
+        CriticalSectionMonoUlidGenerator::new(MockTime { millis: 1 }, CountingRand::new())
+            .with_random_increment(1..=16);
+    run_random_increment_steps_within_range(|| generator.poll_id());
+
+    let generator: CriticalSectionMonoUlidGenerator<ULID, _, _> =
+        CriticalSectionMonoUlidGenerator::from_components(
+            0,
+            ULID::max_random() - 3,
+            FixedTime,
+            MaxRand,
+        )
+        .with_random_increment(1..=8);
+    run_random_increment_waits_when_step_does_not_fit(
+        || generator.poll_id(),
+        || generator.last_id(),
+    );
+}
+
+#[test]
+fn random_increment_of_exactly_one_fills_the_random_component() {
+    let generator: BasicMonoUlidGenerator<ULID, _, _> =
+        BasicMonoUlidGenerator::from_components(0, ULID::max_random() - 1, FixedTime, MaxRand)
+            .with_random_increment(1..=1);
+    assert_eq!(
+        generator.poll_id().unwrap_ready(),
+        ULID::from_components(0, ULID::max_random())
+    );
+    assert_eq!(generator.poll_id().unwrap_pending(), 1);
+}
+
+#[test]
+#[should_panic = "random increment range must be non-empty and start above zero"]
+fn random_increment_rejects_zero_step() {
+    let _ =
+        BasicMonoUlidGenerator::<ULID, _, _>::new(FixedTime, MinRand).with_random_increment(0..=4);
+}
+
+fn run_node_generators_never_collide<G>(with_node: impl Fn(u128, FixedTime, MinRand) -> G)
+where
+    G: UlidGenerator<NodeUlid, FixedTime, MinRand>,
+{
+    // Both generators draw identical random bits, so only the node keeps
+    // their IDs apart.
+    let generators = [
+        with_node(1, FixedTime, MinRand),
+        with_node(NodeUlid::max_node(), FixedTime, MinRand),
+    ];
+    let mut seen = HashSet::new();
+    for (generator, node) in generators.iter().zip([1, NodeUlid::max_node()]) {
+        let mut ids = HashSet::new();
+        for _ in 0..64 {
+            let id = generator.try_poll_id().unwrap().unwrap_ready();
+            assert_eq!(id.node(), node);
+            ids.insert(id);
+        }
+        assert!(seen.is_disjoint(&ids));
+        seen.extend(ids);
+    }
+}
+
+#[test]
+fn basic_generator_node_test() {
+    run_node_generators_never_collide(BasicUlidGenerator::with_node);
+}
+
+#[test]
+fn basic_generator_mono_node_test() {
+    run_node_generators_never_collide(BasicMonoUlidGenerator::with_node);
+}
+
+#[test]
+fn lock_generator_mono_node_test() {
+    run_node_generators_never_collide(LockMonoUlidGenerator::with_node);
+}
+
+#[test]
+#[cfg(target_has_atomic = "128")]
+fn atomic_generator_mono_node_test() {
+    use crate::generator::AtomicMonoUlidGenerator;
+
+    run_node_generators_never_collide(AtomicMonoUlidGenerator::with_node);
+}
+
+#[test]
+fn seqlock_generator_mono_node_test() {
+    run_node_generators_never_collide(SeqLockMonoUlidGenerator::with_node);
+}
+
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_mono_node_test() {
+    use crate::generator::CriticalSectionMonoUlidGenerator;
+
+    run_node_generators_never_collide(CriticalSectionMonoUlidGenerator::with_node);
+}
+
+#[test]
+fn node_is_kept_across_rollover_and_random_increments() {
+    let shared_time = SharedMockStepTime::new(vec![42, 43], 0);
+    let generator: BasicMonoUlidGenerator<NodeUlid, _, _> =
+        BasicMonoUlidGenerator::with_node(9, shared_time.clone(), CountingRand::new())
+            .with_random_increment(1..=16);
+
+    let first = generator.poll_id().unwrap_ready();
+    assert_eq!(first, NodeUlid::from_node_components(42, 9, 0));
+    let second = generator.poll_id().unwrap_ready();
+    assert_eq!(second, NodeUlid::from_node_components(42, 9, 2));
+
+    shared_time.clock.index.set(1);
+    let third = generator.poll_id().unwrap_ready();
+    assert_eq!(third, NodeUlid::from_node_components(43, 9, 2));
+}
+
+#[test]
+fn node_generator_advance_to_orders_after_observed_id() {
+    let generator: BasicMonoUlidGenerator<NodeUlid, _, _> =
+        BasicMonoUlidGenerator::with_node(3, MockTime { millis: 100 }, MinRand);
+
+    // A lower node in the same tick: continue from the bottom of our node.
+    let observed = NodeUlid::from_node_components(100, 2, 42);
+    generator.advance_to(observed);
+    let id = generator.poll_id().unwrap_ready();
+    assert!(id > observed);
+    assert_eq!(id, NodeUlid::from_node_components(100, 3, 1));
+
+    // A higher node in the same tick can't be overtaken until the next tick.
+    generator.advance_to(NodeUlid::from_node_components(100, 4, 0));
+    assert_eq!(generator.poll_id().unwrap_pending(), 1);
+    assert_eq!(generator.last_id().node(), 3);
+}
+
+#[test]
+#[should_panic = "does not fit the layout"]
+fn node_generator_rejects_oversized_node() {
+    let _ = BasicMonoUlidGenerator::<NodeUlid, _, _>::with_node(
+        NodeUlid::max_node() + 1,
+        FixedTime,
+        MinRand,
+    );
+}
+
 #[test]
 fn basic_generator_mono_rollover_test() {
     let shared_time = SharedMockStepTime::new(vec![42, 43], 0);
@@ -328,6 +842,25 @@ fn atomic_generator_mono_rollover_test() {
     run_generator_handles_rollover(&generator, &shared_time);
 }
 
+#[test]
+fn seqlock_generator_mono_rollover_test() {
+    let shared_time = SharedMockStepTime::new(vec![42, 43], 0);
+    let generator: SeqLockMonoUlidGenerator<ULID, _, _> =
+        SeqLockMonoUlidGenerator::new(shared_time.clone(), MaxRand);
+    run_generator_handles_rollover(&generator, &shared_time);
+}
+
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_mono_rollover_test() {
+    use crate::generator::CriticalSectionMonoUlidGenerator;
+
+    let shared_time = SharedMockStepTime::new(vec![42, 43], 0);
+    let generator: CriticalSectionMonoUlidGenerator<ULID, _, _> =
+        CriticalSectionMonoUlidGenerator::new(shared_time.clone(), MaxRand);
+    run_generator_handles_rollover(&generator, &shared_time);
+}
+
 #[test]
 fn basic_generator_monotonic_clock_random_increments() {
     let clock = MonotonicClock::default();
@@ -363,6 +896,15 @@ fn atomic_generator_monotonic_clock_random_increments() {
     run_generator_monotonic(&generator);
 }
 
+#[test]
+fn seqlock_generator_monotonic_clock_random_increments() {
+    let clock = MonotonicClock::default();
+    let rand = ThreadRandom;
+    let generator: SeqLockMonoUlidGenerator<ULID, _, _> =
+        SeqLockMonoUlidGenerator::new(clock, rand);
+    run_generator_monotonic(&generator);
+}
+
 #[test]
 fn lock_generator_threaded_monotonic() {
     let clock = MonotonicClock::default();
@@ -384,6 +926,61 @@ fn atomic_generator_threaded_monotonic() {
     });
 }
 
+#[test]
+fn seqlock_generator_threaded_monotonic() {
+    let clock = MonotonicClock::default();
+    let rand = ThreadRandom;
+    run_generator_monotonic_threaded(move || {
+        SeqLockMonoUlidGenerator::<ULID, _, _>::new(clock.clone(), rand.clone())
+    });
+}
+
+#[test]
+#[cfg(feature = "critical-section")]
+fn critical_section_generator_threaded_monotonic() {
+    use crate::generator::CriticalSectionMonoUlidGenerator;
+
+    let clock = MonotonicClock::default();
+    let rand = ThreadRandom;
+    run_generator_monotonic_threaded(move || {
+        CriticalSectionMonoUlidGenerator::<ULID, _, _>::new(clock.clone(), rand.clone())
+    });
+}
+
+#[test]
+fn seqlock_generator_threaded_monotonic_quantized_clock() {
+    // Coarse ticks keep many threads contending on the same timestamp.
+    let clock = MonotonicClock::<8>::with_epoch(UNIX_EPOCH);
+    let rand = ThreadRandom;
+    run_generator_monotonic_threaded(move || {
+        SeqLockMonoUlidGenerator::<ULID, _, _>::new(clock.clone(), rand.clone())
+    });
+}
+
+#[test]
+fn seqlock_generator_threads_observe_increasing_ids() {
+    const THREADS: usize = 8;
+    const IDS_PER_THREAD: usize = 32_768;
+
+    let generator: SeqLockMonoUlidGenerator<ULID, _, _> =
+        SeqLockMonoUlidGenerator::new(MonotonicClock::default(), ThreadRandom);
+
+    // A torn read of the split state would surface as an ID that does not
+    // strictly increase within a thread.
+    scope(|s| {
+        for _ in 0..THREADS {
+            s.spawn(|| {
+                let mut prev = generator.next_id(|_| std::thread::yield_now());
+                for _ in 0..IDS_PER_THREAD {
+                    let id = generator.next_id(|_| std::thread::yield_now());
+                    assert!(id > prev, "{id:?} <= {prev:?}");
+                    prev = id;
+                }
+            });
+        }
+    });
+}
+
 #[cfg(not(feature = "parking-lot"))]
 #[test]
 fn lock_is_poisoned_on_panic_std_mutex() {
@@ -456,6 +1053,14 @@ fn atomic_mono_can_call_next_id() {
     let _id: ULID = UlidGenerator::next_id(&generator, backoff);
 }
 
+#[test]
+fn seqlock_mono_can_call_next_id() {
+    let generator = SeqLockMonoUlidGenerator::new(MonotonicClock::default(), ThreadRandom);
+    let backoff = |_| core::hint::spin_loop();
+    let _id: ULID = generator.next_id(backoff);
+    let _id: ULID = UlidGenerator::next_id(&generator, backoff);
+}
+
 #[cfg(feature = "parking-lot")]
 #[test]
 fn lock_mono_can_call_next_id() {
@@ -464,3 +1069,35 @@ fn lock_mono_can_call_next_id() {
     let _id: ULID = generator.next_id(backoff);
     let _id: ULID = UlidGenerator::next_id(&generator, backoff);
 }
+
+#[cfg(feature = "stats")]
+#[test]
+fn basic_generator_mono_stats_count_each_outcome() {
+    let shared_time = SharedMockStepTime::new(vec![10, 0], 0);
+    let generator: BasicMonoUlidGenerator<ULID, _, _> =
+        BasicMonoUlidGenerator::from_components(10, 0, shared_time.clone(), MinRand);
+
+    generator.poll_id().unwrap_ready();
+    shared_time.clock.index.set(1);
+    assert_eq!(generator.poll_id().unwrap_pending(), 10);
+
+    let stats = generator.stats();
+    assert_eq!(stats.ids_issued, 1);
+    assert_eq!(stats.pending_clock_behind, 1);
+    assert_eq!(stats.max_regression, 10);
+    assert_eq!(stats.pending(), 1);
+}
+
+#[cfg(feature = "stats")]
+#[test]
+fn seqlock_generator_mono_stats_count_exhaustion() {
+    let generator: SeqLockMonoUlidGenerator<ULID, _, _> =
+        SeqLockMonoUlidGenerator::from_components(0, ULID::max_random(), FixedTime, MinRand);
+    generator.poll_id().unwrap_pending();
+    generator.poll_id().unwrap_pending();
+
+    let stats = generator.stats();
+    assert_eq!(stats.ids_issued, 0);
+    assert_eq!(stats.pending_exhausted, 2);
+    assert_eq!(stats.pending_contention, 0);
+}
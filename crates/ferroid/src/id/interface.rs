@@ -75,3 +75,27 @@ pub trait Id: Copy + Clone + PartialOrd + Ord + PartialEq + Eq + Hash + fmt::Deb
     /// Converts a raw type into this type
     fn from_raw(raw: Self::Ty) -> Self;
 }
+
+/// Returns how long a time source of type `T` can run before its timestamp
+/// first exceeds `max_timestamp`, starting from `now`.
+///
+/// Unlike [`TimeSource::units_to_duration`], the conversion is done in 128
+/// bits, so spans beyond ~584 years (e.g. the 48-bit ULID timestamp) do not
+/// saturate.
+///
+/// [`TimeSource::units_to_duration`]: crate::time::TimeSource::units_to_duration
+#[cfg(any(feature = "snowflake", feature = "ulid"))]
+pub(crate) fn lifetime<Ty: ToU64, T: crate::time::TimeSource<Ty>>(
+    now: Ty,
+    max_timestamp: Ty,
+) -> core::time::Duration {
+    let (now, max_timestamp) = (now.to_u64(), max_timestamp.to_u64());
+    if now > max_timestamp {
+        return core::time::Duration::ZERO;
+    }
+    let nanos =
+        (u128::from(max_timestamp - now) + 1).saturating_mul(u128::from(T::GRANULARITY_NANOS));
+    let secs = u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX);
+    #[allow(clippy::cast_possible_truncation)]
+    core::time::Duration::new(secs, (nanos % 1_000_000_000) as u32)
+}
@@ -1,5 +1,7 @@
 mod be_bytes;
 mod interface;
+mod reversed;
+mod salted;
 #[cfg(feature = "snowflake")]
 mod snowflake;
 mod to_u64;
@@ -9,6 +11,8 @@ mod utils;
 
 pub use be_bytes::*;
 pub use interface::*;
+pub use reversed::*;
+pub use salted::*;
 #[cfg(feature = "snowflake")]
 pub use snowflake::*;
 pub use to_u64::*;
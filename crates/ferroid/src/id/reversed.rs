@@ -0,0 +1,239 @@
+use core::{fmt, marker::PhantomData};
+
+use crate::id::Id;
+#[cfg(feature = "snowflake")]
+use crate::id::SnowflakeId;
+#[cfg(feature = "ulid")]
+use crate::id::UlidId;
+
+/// An ID stored with every bit inverted, so that it sorts newest-first.
+///
+/// Wide-column stores such as Bigtable and HBase scan row keys in ascending
+/// byte order, so tables that want the most recent rows first key them by
+/// `!id`. `Reversed` stores exactly that value: its raw integer, its Base32
+/// encoding, and its [`Ord`] all descend, while `timestamp()` and the other
+/// component accessors still return the original values.
+///
+/// Because the raw value is the bitwise complement of the ascending ID, keys
+/// already written as `!id` load directly with [`Id::from_raw`].
+///
+/// # ⚠️ Note
+/// Generators rely on IDs growing over time. Generate ascending IDs and wrap
+/// them with [`Reversed::new`] rather than using `Reversed` as a generator's ID
+/// type.
+///
+/// # Example
+/// ```
+/// use ferroid::id::{Reversed, SnowflakeId, SnowflakeTwitterId};
+///
+/// let older = Reversed::new(SnowflakeTwitterId::from_components(1, 0, 0));
+/// let newer = Reversed::new(SnowflakeTwitterId::from_components(2, 0, 0));
+///
+/// assert!(newer < older);
+/// assert_eq!(newer.timestamp(), 2);
+/// assert_eq!(newer.into_ascending(), SnowflakeTwitterId::from_components(2, 0, 0));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Reversed<ID: Id> {
+    raw: ID::Ty,
+    _id: PhantomData<ID>,
+}
+
+impl<ID: Id> Reversed<ID> {
+    /// Wraps an ascending ID, inverting its bits.
+    #[must_use]
+    pub fn new(id: ID) -> Self {
+        Self::from_raw(!id.to_raw())
+    }
+
+    /// Returns the ascending ID this value was created from.
+    #[must_use]
+    pub fn into_ascending(self) -> ID {
+        ID::from_raw(!self.raw)
+    }
+}
+
+impl<ID: Id> From<ID> for Reversed<ID> {
+    fn from(id: ID) -> Self {
+        Self::new(id)
+    }
+}
+
+impl<ID: Id> Id for Reversed<ID> {
+    type Ty = ID::Ty;
+    const ZERO: Self::Ty = ID::ZERO;
+    const ONE: Self::Ty = ID::ONE;
+
+    /// Returns the inverted raw value.
+    fn to_raw(&self) -> Self::Ty {
+        self.raw
+    }
+
+    /// Wraps an already inverted raw value, such as a stored `!id` key.
+    fn from_raw(raw: Self::Ty) -> Self {
+        Self {
+            raw,
+            _id: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "snowflake")]
+impl<ID: SnowflakeId> SnowflakeId for Reversed<ID> {
+    fn timestamp(&self) -> Self::Ty {
+        self.into_ascending().timestamp()
+    }
+
+    fn max_timestamp() -> Self::Ty {
+        ID::max_timestamp()
+    }
+
+    fn machine_id(&self) -> Self::Ty {
+        self.into_ascending().machine_id()
+    }
+
+    fn max_machine_id() -> Self::Ty {
+        ID::max_machine_id()
+    }
+
+    fn sequence(&self) -> Self::Ty {
+        self.into_ascending().sequence()
+    }
+
+    fn max_sequence() -> Self::Ty {
+        ID::max_sequence()
+    }
+
+    fn from_components(timestamp: Self::Ty, machine_id: Self::Ty, sequence: Self::Ty) -> Self {
+        Self::new(ID::from_components(timestamp, machine_id, sequence))
+    }
+
+    fn is_valid(&self) -> bool {
+        self.into_ascending().is_valid()
+    }
+
+    fn into_valid(self) -> Self {
+        Self::new(self.into_ascending().into_valid())
+    }
+}
+
+#[cfg(feature = "ulid")]
+impl<ID: UlidId> UlidId for Reversed<ID> {
+    fn timestamp(&self) -> Self::Ty {
+        self.into_ascending().timestamp()
+    }
+
+    fn random(&self) -> Self::Ty {
+        self.into_ascending().random()
+    }
+
+    fn max_timestamp() -> Self::Ty {
+        ID::max_timestamp()
+    }
+
+    fn max_random() -> Self::Ty {
+        ID::max_random()
+    }
+
+    fn node(&self) -> Self::Ty {
+        self.into_ascending().node()
+    }
+
+    fn max_node() -> Self::Ty {
+        ID::max_node()
+    }
+
+    fn from_components(timestamp: Self::Ty, random: Self::Ty) -> Self {
+        Self::new(ID::from_components(timestamp, random))
+    }
+
+    fn from_node_components(timestamp: Self::Ty, node: Self::Ty, random: Self::Ty) -> Self {
+        Self::new(ID::from_node_components(timestamp, node, random))
+    }
+
+    fn is_valid(&self) -> bool {
+        self.into_ascending().is_valid()
+    }
+
+    fn into_valid(self) -> Self {
+        Self::new(self.into_ascending().into_valid())
+    }
+}
+
+impl<ID: Id> fmt::Debug for Reversed<ID> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Reversed")
+            .field(&self.into_ascending())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "snowflake")]
+    #[test]
+    fn reversed_snowflake_sorts_newest_first() {
+        use crate::id::SnowflakeTwitterId;
+
+        let ids = [
+            SnowflakeTwitterId::from_components(1, 5, 0),
+            SnowflakeTwitterId::from_components(1, 5, 1),
+            SnowflakeTwitterId::from_components(2, 0, 0),
+        ];
+        let mut reversed = ids.map(Reversed::new);
+        reversed.sort();
+
+        assert_eq!(
+            reversed.map(Reversed::into_ascending),
+            [ids[2], ids[1], ids[0]]
+        );
+        assert_eq!(reversed[0].timestamp(), 2);
+        assert_eq!(reversed[1].machine_id(), 5);
+        assert_eq!(reversed[1].sequence(), 1);
+    }
+
+    #[cfg(feature = "snowflake")]
+    #[test]
+    fn reversed_snowflake_raw_is_the_complement() {
+        use crate::id::SnowflakeTwitterId;
+
+        let id = SnowflakeTwitterId::from_components(42, 7, 3);
+        let reversed = Reversed::new(id);
+        assert_eq!(reversed.to_raw(), !id.to_raw());
+        assert_eq!(
+            Reversed::<SnowflakeTwitterId>::from_raw(!id.to_raw()),
+            reversed
+        );
+        assert_eq!(
+            Reversed::<SnowflakeTwitterId>::from_components(42, 7, 3),
+            reversed
+        );
+        assert!(reversed.is_valid());
+        assert!(!Reversed::<SnowflakeTwitterId>::from_raw(0).is_valid());
+    }
+
+    #[cfg(all(feature = "ulid", feature = "base32", feature = "alloc"))]
+    #[test]
+    fn reversed_ulid_base32_sorts_newest_first() {
+        use alloc::{string::String, vec::Vec};
+
+        use crate::{base32::Base32UlidExt, id::ULID};
+
+        let older = Reversed::new(ULID::from_components(1, 0));
+        let newer = Reversed::new(ULID::from_components(2, ULID::max_random()));
+
+        let mut encoded: Vec<String> = [older, newer]
+            .iter()
+            .map(|id| id.encode().as_string())
+            .collect();
+        encoded.sort();
+        assert_eq!(encoded[0], newer.encode().as_string());
+
+        let decoded = Reversed::<ULID>::decode(&encoded[1]).unwrap();
+        assert_eq!(decoded, older);
+        assert_eq!(decoded.timestamp(), 1);
+    }
+}
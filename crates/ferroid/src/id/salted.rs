@@ -0,0 +1,497 @@
+use core::{fmt, marker::PhantomData, ops::RangeInclusive};
+
+#[cfg(feature = "snowflake")]
+use crate::id::SnowflakeId;
+#[cfg(feature = "ulid")]
+use crate::id::UlidId;
+use crate::id::{Id, ToU64};
+
+/// An ID prefixed with a hash-derived bucket salt in its top `BITS` bits.
+///
+/// Time-ordered IDs send every new write to the end of the key space, which
+/// turns one range of a distributed KV store or sharded database into a write
+/// hotspot. `Salted` spreads those writes over `2^BITS` buckets: the top `BITS`
+/// bits of the raw value hold a hash of the remaining bits, so consecutive IDs
+/// land in unrelated buckets while each bucket stays time-ordered.
+///
+/// The salt is a pure function of the wrapped ID, so it is recomputed rather
+/// than stored elsewhere, and `timestamp()` and the other component accessors
+/// still return the original values. The wrapped ID must leave its top `BITS`
+/// bits clear, which layouts with at least `BITS` reserved bits always do.
+/// `BITS` must be between 1 and 8. The standard `ULID` reserves no bits, so its
+/// top bits belong to the timestamp and cannot hold a salt, but ULID layouts
+/// defined with enough `reserved` bits can be salted.
+///
+/// Salted keys no longer sort by time across buckets, so a timestamp range
+/// scan becomes one scan per bucket. [`Salted::scan_ranges`] yields those
+/// per-bucket bounds.
+///
+/// # ⚠️ Note
+/// Generators rely on IDs growing over time. Generate unsalted IDs and wrap
+/// them with [`Salted::new`] rather than using `Salted` as a generator's ID
+/// type.
+///
+/// # Example
+/// ```
+/// use ferroid::id::{Salted, SnowflakeId, SnowflakeTwitterId};
+///
+/// // Twitter IDs reserve their top bit, which holds a 2-bucket salt.
+/// type Key = Salted<SnowflakeTwitterId, 1>;
+///
+/// let id = SnowflakeTwitterId::from_components(42, 7, 3);
+/// let key = Key::new(id);
+///
+/// assert_eq!(key.timestamp(), 42);
+/// assert_eq!(key.machine_id(), 7);
+/// assert_eq!(key.into_inner(), id);
+/// assert!(key.bucket() < 2);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Salted<ID: Id, const BITS: u32> {
+    raw: ID::Ty,
+    _id: PhantomData<ID>,
+}
+
+impl<ID: Id, const BITS: u32> Salted<ID, BITS> {
+    /// Number of buckets a salted ID can land in.
+    pub const BUCKETS: u32 = {
+        assert!(BITS >= 1 && BITS <= 8, "salt must be between 1 and 8 bits");
+        1 << BITS
+    };
+
+    /// Wraps an unsalted ID, prefixing it with its bucket.
+    ///
+    /// # Panics
+    /// Panics if the ID uses any of the top `BITS` bits.
+    #[must_use]
+    pub fn new(id: ID) -> Self {
+        let raw = Self::unsalted(id);
+        Self::from_raw(Self::prefix(Self::salt(raw)) | raw)
+    }
+
+    /// Returns the unsalted ID this value was created from.
+    #[must_use]
+    pub fn into_inner(self) -> ID {
+        ID::from_raw(self.raw & !Self::salt_mask())
+    }
+
+    /// Returns the bucket stored in the top `BITS` bits.
+    #[must_use]
+    pub fn bucket(&self) -> ID::Ty {
+        (self.raw & Self::salt_mask()) / Self::unit()
+    }
+
+    /// Returns the raw prefix of every bucket, in ascending order.
+    ///
+    /// Each prefix has only the top `BITS` bits set, so `prefix | id.to_raw()`
+    /// places an unsalted ID in that bucket.
+    pub fn bucket_prefixes() -> impl Iterator<Item = ID::Ty> {
+        (0..Self::BUCKETS).map(|bucket| {
+            #[allow(clippy::cast_possible_truncation)]
+            Self::prefix(ID::Ty::from(bucket as u8))
+        })
+    }
+
+    /// Returns one inclusive key range per bucket that together cover every
+    /// salted ID whose unsalted value lies within `first..=last`.
+    ///
+    /// For a timestamp range scan, pass the smallest ID at the start timestamp
+    /// and the largest ID at the end timestamp. The bounds are scan limits,
+    /// not issued IDs, so they need not carry their own bucket's salt.
+    ///
+    /// # Panics
+    /// Panics if either bound uses any of the top `BITS` bits.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::id::{Id, Salted, SnowflakeId, SnowflakeTwitterId};
+    ///
+    /// type Key = Salted<SnowflakeTwitterId, 1>;
+    ///
+    /// let first = SnowflakeTwitterId::from_components(100, 0, 0);
+    /// let last = SnowflakeTwitterId::from_components(
+    ///     200,
+    ///     SnowflakeTwitterId::max_machine_id(),
+    ///     SnowflakeTwitterId::max_sequence(),
+    /// );
+    ///
+    /// let key = Key::new(SnowflakeTwitterId::from_components(150, 1, 0));
+    /// let ranges: Vec<_> = Key::scan_ranges(first, last).collect();
+    ///
+    /// assert_eq!(ranges.len(), 2);
+    /// assert_eq!(ranges.iter().filter(|range| range.contains(&key)).count(), 1);
+    /// ```
+    pub fn scan_ranges(first: ID, last: ID) -> impl Iterator<Item = RangeInclusive<Self>> {
+        let first = Self::unsalted(first);
+        let last = Self::unsalted(last);
+        Self::bucket_prefixes()
+            .map(move |prefix| Self::from_raw(prefix | first)..=Self::from_raw(prefix | last))
+    }
+
+    /// Returns the salt an unsalted raw value hashes to.
+    fn salt(raw: ID::Ty) -> ID::Ty {
+        // Fold the value into 64 bits, then mix it with the SplitMix64
+        // finalizer so that neighbouring IDs land in unrelated buckets.
+        let mut hash = 0_u64;
+        let mut rest = raw;
+        let mut width = core::mem::size_of::<ID::Ty>() * 8;
+        loop {
+            // Only types wider than 64 bits are ever shifted by 64.
+            let low = if width > 64 {
+                let high = rest >> 32_u32 >> 32_u32;
+                let low = rest ^ (high << 32_u32 << 32_u32);
+                rest = high;
+                low
+            } else {
+                rest
+            };
+            hash ^= low.to_u64();
+            hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            hash ^= hash >> 31;
+            if width <= 64 {
+                break;
+            }
+            width -= 64;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        ID::Ty::from((hash >> (64 - BITS)) as u8)
+    }
+
+    /// Returns the raw value of `id`, checking that its salt bits are clear.
+    fn unsalted(id: ID) -> ID::Ty {
+        let raw = id.to_raw();
+        assert!(
+            raw & Self::salt_mask() == ID::ZERO,
+            "ID {id:?} uses the top {BITS} bits reserved for the salt"
+        );
+        raw
+    }
+
+    /// Shifts a bucket into the top `BITS` bits.
+    fn prefix(bucket: ID::Ty) -> ID::Ty {
+        bucket * Self::unit()
+    }
+
+    /// Returns the lowest bit of the salt field.
+    fn unit() -> ID::Ty {
+        // Evaluating `BUCKETS` rejects out-of-range `BITS` at compile time.
+        let _ = Self::BUCKETS;
+        (!ID::ZERO >> BITS) + ID::ONE
+    }
+
+    /// Returns a mask covering the top `BITS` bits.
+    fn salt_mask() -> ID::Ty {
+        !(!ID::ZERO >> BITS)
+    }
+}
+
+impl<ID: Id, const BITS: u32> From<ID> for Salted<ID, BITS> {
+    fn from(id: ID) -> Self {
+        Self::new(id)
+    }
+}
+
+impl<ID: Id, const BITS: u32> Id for Salted<ID, BITS> {
+    type Ty = ID::Ty;
+    const ZERO: Self::Ty = ID::ZERO;
+    const ONE: Self::Ty = ID::ONE;
+
+    /// Returns the salted raw value.
+    fn to_raw(&self) -> Self::Ty {
+        self.raw
+    }
+
+    /// Wraps an already salted raw value, such as a stored key.
+    fn from_raw(raw: Self::Ty) -> Self {
+        Self {
+            raw,
+            _id: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "snowflake")]
+impl<ID: SnowflakeId, const BITS: u32> SnowflakeId for Salted<ID, BITS> {
+    fn timestamp(&self) -> Self::Ty {
+        self.into_inner().timestamp()
+    }
+
+    fn max_timestamp() -> Self::Ty {
+        ID::max_timestamp()
+    }
+
+    fn machine_id(&self) -> Self::Ty {
+        self.into_inner().machine_id()
+    }
+
+    fn max_machine_id() -> Self::Ty {
+        ID::max_machine_id()
+    }
+
+    fn sequence(&self) -> Self::Ty {
+        self.into_inner().sequence()
+    }
+
+    fn max_sequence() -> Self::Ty {
+        ID::max_sequence()
+    }
+
+    fn from_components(timestamp: Self::Ty, machine_id: Self::Ty, sequence: Self::Ty) -> Self {
+        Self::new(ID::from_components(timestamp, machine_id, sequence))
+    }
+
+    /// Returns `true` if the unsalted ID is valid and the stored bucket matches
+    /// its salt.
+    fn is_valid(&self) -> bool {
+        let inner = self.into_inner();
+        inner.is_valid() && Self::new(inner) == *self
+    }
+
+    fn into_valid(self) -> Self {
+        Self::new(self.into_inner().into_valid())
+    }
+}
+
+#[cfg(feature = "ulid")]
+impl<ID: UlidId, const BITS: u32> UlidId for Salted<ID, BITS> {
+    fn timestamp(&self) -> Self::Ty {
+        self.into_inner().timestamp()
+    }
+
+    fn random(&self) -> Self::Ty {
+        self.into_inner().random()
+    }
+
+    fn max_timestamp() -> Self::Ty {
+        ID::max_timestamp()
+    }
+
+    fn max_random() -> Self::Ty {
+        ID::max_random()
+    }
+
+    fn node(&self) -> Self::Ty {
+        self.into_inner().node()
+    }
+
+    fn max_node() -> Self::Ty {
+        ID::max_node()
+    }
+
+    fn from_components(timestamp: Self::Ty, random: Self::Ty) -> Self {
+        Self::new(ID::from_components(timestamp, random))
+    }
+
+    fn from_node_components(timestamp: Self::Ty, node: Self::Ty, random: Self::Ty) -> Self {
+        Self::new(ID::from_node_components(timestamp, node, random))
+    }
+
+    /// Returns `true` if the unsalted ID is valid and the stored bucket matches
+    /// its salt.
+    fn is_valid(&self) -> bool {
+        let inner = self.into_inner();
+        inner.is_valid() && Self::new(inner) == *self
+    }
+
+    fn into_valid(self) -> Self {
+        Self::new(self.into_inner().into_valid())
+    }
+}
+
+impl<ID: Id, const BITS: u32> fmt::Debug for Salted<ID, BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Salted")
+            .field("bucket", &self.bucket())
+            .field("id", &self.into_inner())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // Local layout expansions are linted as if hand-written.
+    #[allow(clippy::wrong_self_convention)]
+    mod layouts {
+        #[cfg(feature = "snowflake")]
+        crate::define_snowflake_id!(
+            /// A 32-bit layout with a reserved top bit.
+            Snowflake32, u32,
+            reserved: 1,
+            timestamp: 15,
+            machine_id: 8,
+            sequence: 8
+        );
+
+        #[cfg(feature = "ulid")]
+        crate::define_ulid!(
+            /// A ULID layout that reserves its top byte.
+            ReservedUlid, u128,
+            reserved: 8,
+            timestamp: 48,
+            random: 72
+        );
+    }
+
+    #[cfg(feature = "snowflake")]
+    #[test]
+    fn salted_snowflake_spreads_consecutive_ids() {
+        use std::collections::HashSet;
+
+        use crate::id::SnowflakeTwitterId;
+
+        let buckets: HashSet<_> = (0..64)
+            .map(|timestamp| {
+                Salted::<SnowflakeTwitterId, 1>::new(SnowflakeTwitterId::from_components(
+                    timestamp, 0, 0,
+                ))
+                .bucket()
+            })
+            .collect();
+        assert_eq!(buckets.len(), 2);
+
+        let id = SnowflakeTwitterId::from_components(42, 7, 3);
+        let salted = Salted::<SnowflakeTwitterId, 1>::new(id);
+        assert_eq!(salted.timestamp(), 42);
+        assert_eq!(salted.machine_id(), 7);
+        assert_eq!(salted.sequence(), 3);
+        assert_eq!(
+            <Salted<SnowflakeTwitterId, 1> as SnowflakeId>::from_components(42, 7, 3),
+            salted
+        );
+        assert_eq!(
+            Salted::<SnowflakeTwitterId, 1>::from_raw(salted.to_raw()),
+            salted
+        );
+        assert!(salted.is_valid());
+
+        let moved = Salted::<SnowflakeTwitterId, 1>::from_raw(salted.to_raw() ^ (1 << 63));
+        assert!(!moved.is_valid());
+        assert_eq!(moved.into_valid(), salted);
+    }
+
+    #[cfg(feature = "snowflake")]
+    #[test]
+    #[should_panic(expected = "reserved for the salt")]
+    fn salted_rejects_ids_using_salt_bits() {
+        use crate::id::SnowflakeTwitterId;
+
+        let _ = Salted::<SnowflakeTwitterId, 2>::new(SnowflakeTwitterId::from_components(
+            SnowflakeTwitterId::max_timestamp(),
+            0,
+            0,
+        ));
+    }
+
+    #[cfg(feature = "snowflake")]
+    #[test]
+    fn salted_snowflake_accepts_current_timestamps() {
+        use std::{time::SystemTime, vec::Vec};
+
+        use crate::{id::SnowflakeTwitterId, time::TWITTER_EPOCH};
+
+        type Key = Salted<SnowflakeTwitterId, 1>;
+
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .saturating_sub(TWITTER_EPOCH);
+        let timestamp = u64::try_from(now.as_millis()).unwrap();
+
+        let id = SnowflakeTwitterId::from_components(
+            timestamp,
+            SnowflakeTwitterId::max_machine_id(),
+            SnowflakeTwitterId::max_sequence(),
+        );
+        let key = Key::new(id);
+        assert_eq!(key.timestamp(), timestamp);
+        assert_eq!(key.into_inner(), id);
+        assert!(key.is_valid());
+
+        let first = SnowflakeTwitterId::from_components(timestamp - 1_000, 0, 0);
+        let ranges: Vec<_> = Key::scan_ranges(first, id).collect();
+        assert_eq!(
+            ranges.iter().filter(|range| range.contains(&key)).count(),
+            1
+        );
+    }
+
+    #[cfg(feature = "snowflake")]
+    #[test]
+    fn salted_snowflake_supports_32_bit_layouts() {
+        use std::collections::HashSet;
+
+        use layouts::Snowflake32;
+
+        type Key = Salted<Snowflake32, 1>;
+
+        let buckets: HashSet<_> = (0..64)
+            .map(|timestamp| Key::new(Snowflake32::from_components(timestamp, 0, 0)).bucket())
+            .collect();
+        assert_eq!(buckets.len(), 2);
+
+        let id = Snowflake32::from_components(
+            Snowflake32::max_timestamp(),
+            Snowflake32::max_machine_id(),
+            Snowflake32::max_sequence(),
+        );
+        let key = Key::new(id);
+        assert_eq!(key.timestamp(), Snowflake32::max_timestamp());
+        assert_eq!(key.into_inner(), id);
+        assert!(key.is_valid());
+    }
+
+    #[cfg(feature = "ulid")]
+    #[test]
+    fn salted_reserved_ulid_accepts_current_timestamps() {
+        use std::{time::SystemTime, vec::Vec};
+
+        use layouts::ReservedUlid;
+
+        type Key = Salted<ReservedUlid, 8>;
+
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap();
+        let timestamp = now.as_millis();
+
+        let id = ReservedUlid::from_components(timestamp, 0xdead_beef);
+        let key = Key::new(id);
+        assert_eq!(key.timestamp(), timestamp);
+        assert_eq!(key.random(), 0xdead_beef);
+        assert_eq!(key.into_inner(), id);
+        assert!(key.is_valid());
+        assert_eq!(
+            <Key as UlidId>::from_components(timestamp, 0xdead_beef),
+            key
+        );
+
+        let first = ReservedUlid::from_components(timestamp - 1_000, 0);
+        let last = ReservedUlid::from_components(timestamp, ReservedUlid::max_random());
+        let ranges: Vec<_> = Key::scan_ranges(first, last).collect();
+        assert_eq!(ranges.len(), 256);
+        assert_eq!(
+            ranges.iter().filter(|range| range.contains(&key)).count(),
+            1
+        );
+    }
+
+    #[cfg(feature = "ulid")]
+    #[test]
+    #[should_panic(expected = "reserved for the salt")]
+    fn salted_ulid_rejects_current_timestamps() {
+        use std::time::SystemTime;
+
+        use crate::id::ULID;
+
+        // ULIDs reserve no bits, so the salt overlaps the high timestamp bits,
+        // which present-day timestamps already use.
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap();
+        let _ = Salted::<ULID, 8>::new(ULID::from_components(now.as_millis(), 0));
+    }
+}
@@ -1,6 +1,9 @@
-use core::hash::Hash;
+use core::{hash::Hash, time::Duration};
 
-use crate::id::Id;
+use crate::{
+    id::{Id, lifetime},
+    time::TimeSource,
+};
 
 /// A trait representing a layout-compatible Snowflake ID generator.
 ///
@@ -64,6 +67,30 @@ pub trait SnowflakeId: Id {
         Self::from_components(ts, self.machine_id(), Self::ZERO)
     }
 
+    /// Returns how long `clock` can keep producing timestamps that fit this
+    /// layout, or [`Duration::ZERO`] once it has passed
+    /// [`Self::max_timestamp`].
+    ///
+    /// Generators report [`Poll::TimestampOverflow`] from that point on, so
+    /// this is worth exporting as a gauge and alerting on years in advance.
+    ///
+    /// [`Poll::TimestampOverflow`]: crate::generator::Poll::TimestampOverflow
+    fn remaining_lifetime<T: TimeSource<Self::Ty>>(clock: &T) -> Duration {
+        lifetime::<_, T>(clock.current_millis(), Self::max_timestamp())
+    }
+
+    /// Returns the point in time, as a [`Duration`] since the Unix epoch, at
+    /// which a time source of type `T` counting from `epoch` first exceeds
+    /// [`Self::max_timestamp`].
+    ///
+    /// `epoch` is the same offset passed to the clock's `with_epoch`
+    /// constructor (e.g. [`TWITTER_EPOCH`]).
+    ///
+    /// [`TWITTER_EPOCH`]: crate::time::TWITTER_EPOCH
+    fn exhaustion_date<T: TimeSource<Self::Ty>>(epoch: Duration) -> Duration {
+        epoch.saturating_add(lifetime::<_, T>(Self::ZERO, Self::max_timestamp()))
+    }
+
     /// Returns `true` if the ID's internal structure is valid, such as reserved
     /// bits being unset or fields within expected ranges.
     fn is_valid(&self) -> bool;
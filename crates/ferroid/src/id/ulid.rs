@@ -1,6 +1,9 @@
-use core::hash::Hash;
+use core::{hash::Hash, time::Duration};
 
-use crate::id::Id;
+use crate::{
+    id::{Id, lifetime},
+    time::TimeSource,
+};
 
 /// Trait for layout-compatible ULID-style identifiers.
 ///
@@ -23,10 +26,31 @@ pub trait UlidId: Id {
     /// Returns the maximum possible value for the random field.
     fn max_random() -> Self::Ty;
 
+    /// Returns the node portion of the ID, or zero for layouts without a node
+    /// field.
+    fn node(&self) -> Self::Ty {
+        Self::ZERO
+    }
+
+    /// Returns the maximum possible value for the node field, or zero for
+    /// layouts without a node field.
+    fn max_node() -> Self::Ty {
+        Self::ZERO
+    }
+
     /// Constructs a new ULID from its components.
     #[must_use]
     fn from_components(timestamp: Self::Ty, random: Self::Ty) -> Self;
 
+    /// Constructs a new ULID from its components, including the node.
+    ///
+    /// Layouts without a node field ignore `node`.
+    #[must_use]
+    fn from_node_components(timestamp: Self::Ty, node: Self::Ty, random: Self::Ty) -> Self {
+        debug_assert!(node == Self::ZERO, "layout has no node field");
+        Self::from_components(timestamp, random)
+    }
+
     /// Returns true if the current sequence value can be incremented.
     fn has_random_room(&self) -> bool {
         self.random() < Self::max_random()
@@ -40,13 +64,38 @@ pub trait UlidId: Id {
     /// Returns a new ID with the random portion incremented.
     #[must_use]
     fn increment_random(&self) -> Self {
-        Self::from_components(self.timestamp(), self.next_random())
+        Self::from_node_components(self.timestamp(), self.node(), self.next_random())
     }
 
-    /// Returns a new ID for a newer timestamp with sequence reset to zero.
+    /// Returns a new ID for a newer timestamp with a fresh random portion and
+    /// the same node.
     #[must_use]
     fn rollover_to_timestamp(&self, ts: Self::Ty, rand: Self::Ty) -> Self {
-        Self::from_components(ts, rand)
+        Self::from_node_components(ts, self.node(), rand)
+    }
+
+    /// Returns how long `clock` can keep producing timestamps that fit this
+    /// layout, or [`Duration::ZERO`] once it has passed
+    /// [`Self::max_timestamp`].
+    ///
+    /// Generators report [`Poll::TimestampOverflow`] from that point on, so
+    /// this is worth exporting as a gauge and alerting on years in advance.
+    ///
+    /// [`Poll::TimestampOverflow`]: crate::generator::Poll::TimestampOverflow
+    fn remaining_lifetime<T: TimeSource<Self::Ty>>(clock: &T) -> Duration {
+        lifetime::<_, T>(clock.current_millis(), Self::max_timestamp())
+    }
+
+    /// Returns the point in time, as a [`Duration`] since the Unix epoch, at
+    /// which a time source of type `T` counting from `epoch` first exceeds
+    /// [`Self::max_timestamp`].
+    ///
+    /// `epoch` is the same offset passed to the clock's `with_epoch`
+    /// constructor (e.g. [`TWITTER_EPOCH`]).
+    ///
+    /// [`TWITTER_EPOCH`]: crate::time::TWITTER_EPOCH
+    fn exhaustion_date<T: TimeSource<Self::Ty>>(epoch: Duration) -> Duration {
+        epoch.saturating_add(lifetime::<_, T>(Self::ZERO, Self::max_timestamp()))
     }
 
     /// Returns `true` if the ID's internal structure is valid, such as reserved
@@ -101,6 +150,41 @@ pub trait UlidId: Id {
 ///              +----------------+-------------+
 ///              |<-- MSB -- 128 bits -- LSB -->|
 /// ```
+///
+/// ## Example: A node-tagged ULID layout
+///
+/// An optional `node` field between `timestamp` and `random` reserves part of
+/// the random bits for a node or partition number fixed per generator (see
+/// [`BasicMonoUlidGenerator::with_node`]). IDs from generators with different
+/// nodes can never collide, and the origin node can be decoded from any ID.
+///
+/// ```rust
+/// use ferroid::{define_ulid, id::UlidId};
+///
+/// define_ulid!(
+///     MyNodeId, u128,
+///     reserved: 0,
+///     timestamp: 48,
+///     node: 10,
+///     random: 70
+/// );
+///
+/// let id = MyNodeId::from_node_components(1, 7, 42);
+/// assert_eq!(id.node(), 7);
+/// assert_eq!(MyNodeId::max_node(), (1 << 10) - 1);
+/// ```
+///
+/// Which expands to the following bit layout:
+///
+/// ```text
+///  Bit Index:  127            80 79      70 69           0
+///              +----------------+----------+-------------+
+///  Field:      | timestamp (48) | node (10)| random (70) |
+///              +----------------+----------+-------------+
+///              |<----------- MSB -- 128 bits -- LSB ---->|
+/// ```
+///
+/// [`BasicMonoUlidGenerator::with_node`]: crate::generator::BasicMonoUlidGenerator::with_node
 #[cfg_attr(docsrs, doc(cfg(feature = "ulid")))]
 #[macro_export]
 macro_rules! define_ulid {
@@ -110,6 +194,23 @@ macro_rules! define_ulid {
         reserved: $reserved_bits:expr,
         timestamp: $timestamp_bits:expr,
         random: $random_bits:expr
+    ) => {
+        $crate::define_ulid!(
+            $(#[$meta])*
+            $name, $int,
+            reserved: $reserved_bits,
+            timestamp: $timestamp_bits,
+            node: 0,
+            random: $random_bits
+        );
+    };
+    (
+        $(#[$meta:meta])*
+        $name:ident, $int:ty,
+        reserved: $reserved_bits:expr,
+        timestamp: $timestamp_bits:expr,
+        node: $node_bits:expr,
+        random: $random_bits:expr
     ) => {
         $(#[$meta])*
         #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -122,7 +223,7 @@ macro_rules! define_ulid {
             // Compile-time check: total bit width _must_ equal the backing
             // type. This is to avoid aliasing surprises.
             assert!(
-                $reserved_bits + $timestamp_bits + $random_bits == <$int>::BITS,
+                $reserved_bits + $timestamp_bits + $node_bits + $random_bits == <$int>::BITS,
                 "Layout must match underlying type width"
             );
         };
@@ -131,32 +232,43 @@ macro_rules! define_ulid {
         impl $name {
             pub const RESERVED_BITS: $int = $reserved_bits;
             pub const TIMESTAMP_BITS: $int = $timestamp_bits;
+            pub const NODE_BITS: $int = $node_bits;
             pub const RANDOM_BITS: $int = $random_bits;
 
             pub const RANDOM_SHIFT: $int = 0;
-            pub const TIMESTAMP_SHIFT: $int = Self::RANDOM_SHIFT + Self::RANDOM_BITS;
+            pub const NODE_SHIFT: $int = Self::RANDOM_SHIFT + Self::RANDOM_BITS;
+            pub const TIMESTAMP_SHIFT: $int = Self::NODE_SHIFT + Self::NODE_BITS;
             pub const RESERVED_SHIFT: $int = Self::TIMESTAMP_SHIFT + Self::TIMESTAMP_BITS;
 
             pub const RESERVED_MASK: $int = ((1 << Self::RESERVED_BITS) - 1);
             pub const TIMESTAMP_MASK: $int = ((1 << Self::TIMESTAMP_BITS) - 1);
+            pub const NODE_MASK: $int = ((1 << Self::NODE_BITS) - 1);
             pub const RANDOM_MASK: $int = ((1 << Self::RANDOM_BITS) - 1);
 
             const fn valid_mask() -> $int {
                 (Self::TIMESTAMP_MASK << Self::TIMESTAMP_SHIFT) |
+                (Self::NODE_MASK << Self::NODE_SHIFT) |
                 (Self::RANDOM_MASK << Self::RANDOM_SHIFT)
             }
 
             #[must_use]
             pub const fn from_components(timestamp: $int, random: $int) -> Self {
+                Self::from_node_components(timestamp, 0, random)
+            }
+
+            #[must_use]
+            pub const fn from_node_components(timestamp: $int, node: $int, random: $int) -> Self {
                 // Random bits can frequencly overflow, but this is okay since
                 // they're masked. We don't need a debug assertion here because
-                // this is expected behavior. However, the timestamp should
-                // never overflow.
+                // this is expected behavior. However, the timestamp and node
+                // should never overflow.
                 debug_assert!(timestamp <= Self::TIMESTAMP_MASK, "timestamp overflow");
+                debug_assert!(node <= Self::NODE_MASK, "node overflow");
 
                 let t = (timestamp & Self::TIMESTAMP_MASK) << Self::TIMESTAMP_SHIFT;
+                let n = (node & Self::NODE_MASK) << Self::NODE_SHIFT;
                 let r = (random & Self::RANDOM_MASK) << Self::RANDOM_SHIFT;
-                Self { id: t | r }
+                Self { id: t | n | r }
             }
 
             /// Extracts the timestamp from the packed ID.
@@ -164,6 +276,11 @@ macro_rules! define_ulid {
             pub const fn timestamp(&self) -> $int {
                 (self.id >> Self::TIMESTAMP_SHIFT) & Self::TIMESTAMP_MASK
             }
+            /// Extracts the node from the packed ID.
+            #[must_use]
+            pub const fn node(&self) -> $int {
+                (self.id >> Self::NODE_SHIFT) & Self::NODE_MASK
+            }
             /// Extracts the random number from the packed ID.
             #[must_use]
             pub const fn random(&self) -> $int {
@@ -175,6 +292,12 @@ macro_rules! define_ulid {
             pub const fn max_timestamp() -> $int {
                 Self::TIMESTAMP_MASK
             }
+            /// Returns the maximum representable node value based on
+            /// `Self::NODE_BITS`.
+            #[must_use]
+            pub const fn max_node() -> $int {
+                Self::NODE_MASK
+            }
             /// Returns the maximum representable randome value based on
             /// `Self::RANDOM_BIT`.
             #[must_use]
@@ -298,7 +421,7 @@ macro_rules! define_ulid {
                     let timestamp = datetime
                         .duration_since(std::time::SystemTime::UNIX_EPOCH)
                         .unwrap_or(core::time::Duration::ZERO)
-                        .as_millis();
+                        .as_millis() as $int;
                     let random = rng.rand();
                     Self::from_components(timestamp, random)
                 }
@@ -338,10 +461,22 @@ macro_rules! define_ulid {
                 Self::RANDOM_MASK
             }
 
+            fn node(&self) -> Self::Ty {
+                self.node()
+            }
+
+            fn max_node() -> Self::Ty {
+                Self::NODE_MASK
+            }
+
             fn from_components(timestamp: $int, random: $int) -> Self {
                 Self::from_components(timestamp, random)
             }
 
+            fn from_node_components(timestamp: $int, node: $int, random: $int) -> Self {
+                Self::from_node_components(timestamp, node, random)
+            }
+
             fn is_valid(&self) -> bool {
                 (self.to_raw() & !Self::valid_mask()) == 0
             }
@@ -427,6 +562,9 @@ macro_rules! define_ulid {
                 let mut dbg = f.debug_struct(name);
                 dbg.field("id", &format_args!("{:} (0x{:x})", self.to_raw(), self.to_raw()));
                 dbg.field("timestamp", &format_args!("{:} (0x{:x})", self.timestamp(), self.timestamp()));
+                if Self::NODE_BITS > 0 {
+                    dbg.field("node", &format_args!("{:} (0x{:x})", self.node(), self.node()));
+                }
                 dbg.field("random", &format_args!("{:} (0x{:x})", self.random(), self.random()));
                 dbg.finish()
             }
@@ -535,4 +673,60 @@ mod tests {
         assert_eq!(id.timestamp(), 42);
         assert_eq!(id.random(), 42);
     }
+
+    // Local `define_ulid!` expansions are linted as if hand-written.
+    #[allow(clippy::wrong_self_convention)]
+    mod layouts {
+        define_ulid!(
+            /// A ULID with a 10-bit node field.
+            NodeUlid, u128,
+            reserved: 0,
+            timestamp: 48,
+            node: 10,
+            random: 70
+        );
+    }
+    use layouts::NodeUlid;
+
+    #[test]
+    fn node_ulid_fields_and_bounds() {
+        assert_eq!(NodeUlid::max_node(), (1 << 10) - 1);
+        assert_eq!(NodeUlid::max_random(), (1 << 70) - 1);
+
+        let id = NodeUlid::from_node_components(
+            NodeUlid::max_timestamp(),
+            NodeUlid::max_node(),
+            NodeUlid::max_random(),
+        );
+        println!("ID: {id:#?}");
+        assert_eq!(id.to_raw(), u128::MAX);
+        assert!(id.is_valid());
+
+        let id = NodeUlid::from_node_components(5, 3, 1);
+        assert_eq!(id.timestamp(), 5);
+        assert_eq!(id.node(), 3);
+        assert_eq!(id.random(), 1);
+        assert_eq!(id.to_raw(), (5 << 80) | (3 << 70) | 1);
+    }
+
+    #[test]
+    fn node_ulid_random_overflow_does_not_touch_node() {
+        let id = NodeUlid::from_node_components(5, 3, u128::MAX);
+        assert_eq!(id.node(), 3);
+        assert_eq!(id.random(), NodeUlid::max_random());
+
+        let rolled = id.rollover_to_timestamp(6, 7);
+        assert_eq!(rolled, NodeUlid::from_node_components(6, 3, 7));
+
+        let incremented = NodeUlid::from_node_components(5, 3, 1).increment_random();
+        assert_eq!(incremented, NodeUlid::from_node_components(5, 3, 2));
+    }
+
+    #[test]
+    fn ulid_without_node_field_reports_zero_node() {
+        let id = ULID::from_components(5, 1);
+        assert_eq!(id.node(), 0);
+        assert_eq!(ULID::max_node(), 0);
+        assert_eq!(ULID::NODE_BITS, 0);
+    }
 }
@@ -16,6 +16,9 @@ pub mod __internal {
     pub use alloc::string::String;
     #[cfg(feature = "std")]
     pub use std::string::String;
+    // Used for define_thread_local_generator! macro
+    #[cfg(feature = "thread-local")]
+    pub use std::{sync::LazyLock, thread_local};
 }
 
 #[cfg(feature = "base32")]
@@ -24,7 +27,11 @@ pub mod base32;
 pub mod futures;
 pub mod generator;
 pub mod id;
+#[cfg(feature = "snowflake")]
+pub mod machine;
 pub mod rand;
 #[cfg(feature = "serde")]
 pub mod serde;
+#[cfg(all(feature = "testing", target_has_atomic = "64"))]
+pub mod testing;
 pub mod time;
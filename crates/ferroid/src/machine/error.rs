@@ -0,0 +1,41 @@
+use core::fmt;
+
+/// All error variants that a [`MachineIdProvider`] in this crate can emit.
+///
+/// [`MachineIdProvider`]: crate::machine::MachineIdProvider
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Error {
+    /// The resolved machine ID does not fit into the layout's machine ID
+    /// field.
+    OutOfRange,
+
+    /// The source the machine ID is derived from is not available (e.g. an
+    /// unset environment variable or no routable local address).
+    Unavailable,
+
+    /// The source was available but could not be parsed as a machine ID.
+    Invalid,
+
+    /// Every machine ID slot is currently leased by another holder.
+    Exhausted,
+
+    /// An I/O operation failed while resolving or leasing a machine ID.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{self:?}")
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e.kind())
+    }
+}
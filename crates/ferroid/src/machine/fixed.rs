@@ -0,0 +1,105 @@
+use crate::{
+    id::SnowflakeId,
+    machine::{Error, MachineIdProvider, checked_machine_id},
+};
+
+/// A [`MachineIdProvider`] that always returns the same, preconfigured machine
+/// ID.
+///
+/// This is the right choice when machine IDs are assigned by deployment
+/// tooling (e.g. a StatefulSet ordinal baked into the configuration).
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     id::SnowflakeTwitterId,
+///     machine::{Error, MachineIdProvider, StaticMachineId},
+/// };
+///
+/// let provider = StaticMachineId::new(42);
+/// assert_eq!(provider.machine_id::<SnowflakeTwitterId>(), Ok(42));
+///
+/// // Twitter IDs only have 10 machine ID bits.
+/// let provider = StaticMachineId::new(1024);
+/// assert_eq!(
+///     provider.machine_id::<SnowflakeTwitterId>(),
+///     Err(Error::OutOfRange)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StaticMachineId {
+    machine_id: u64,
+}
+
+impl StaticMachineId {
+    /// Creates a provider that always resolves to `machine_id`.
+    pub const fn new(machine_id: u64) -> Self {
+        Self { machine_id }
+    }
+}
+
+impl MachineIdProvider for StaticMachineId {
+    type Err = Error;
+
+    fn machine_id<ID: SnowflakeId>(&self) -> Result<ID::Ty, Self::Err> {
+        checked_machine_id::<ID>(self.machine_id).ok_or(Error::OutOfRange)
+    }
+}
+
+/// A [`MachineIdProvider`] that reads the machine ID from an environment
+/// variable.
+///
+/// The variable is read every time the machine ID is resolved and must contain
+/// a decimal integer that fits into the layout's machine ID field.
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     id::SnowflakeTwitterId,
+///     machine::{EnvMachineId, Error, MachineIdProvider},
+/// };
+///
+/// let provider = EnvMachineId::new("MY_APP_MACHINE_ID_THAT_IS_NOT_SET");
+/// assert_eq!(
+///     provider.machine_id::<SnowflakeTwitterId>(),
+///     Err(Error::Unavailable)
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "machine-id")))]
+#[cfg(feature = "machine-id")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnvMachineId {
+    var: &'static str,
+}
+
+#[cfg(feature = "machine-id")]
+impl EnvMachineId {
+    /// The variable read by [`EnvMachineId::default`].
+    pub const DEFAULT_VAR: &'static str = "FERROID_MACHINE_ID";
+
+    /// Creates a provider that reads the machine ID from `var`.
+    pub const fn new(var: &'static str) -> Self {
+        Self { var }
+    }
+}
+
+#[cfg(feature = "machine-id")]
+impl Default for EnvMachineId {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_VAR)
+    }
+}
+
+#[cfg(feature = "machine-id")]
+impl MachineIdProvider for EnvMachineId {
+    type Err = Error;
+
+    fn machine_id<ID: SnowflakeId>(&self) -> Result<ID::Ty, Self::Err> {
+        let value = std::env::var(self.var).map_err(|e| match e {
+            std::env::VarError::NotPresent => Error::Unavailable,
+            std::env::VarError::NotUnicode(_) => Error::Invalid,
+        })?;
+        let value: u64 = value.trim().parse().map_err(|_| Error::Invalid)?;
+        checked_machine_id::<ID>(value).ok_or(Error::OutOfRange)
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    ffi::OsString,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket},
+};
+
+use crate::{
+    id::SnowflakeId,
+    machine::{Error, MachineIdProvider, reduce_machine_id},
+};
+
+/// A [`MachineIdProvider`] that derives the machine ID from a hash of the
+/// host name.
+///
+/// The host name is hashed with 64-bit FNV-1a and reduced onto
+/// `0..=max_machine_id()`. This is stable across restarts and requires no
+/// configuration, but distinct hosts can collide: with `n` hosts and `m`
+/// machine IDs the collision probability is roughly `n² / 2m`. Prefer a
+/// [`StaticMachineId`] or a [`LeaseAllocator`] when uniqueness must be
+/// guaranteed.
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     id::SnowflakeTwitterId,
+///     machine::{HostnameMachineId, MachineIdProvider},
+/// };
+///
+/// let a = HostnameMachineId::from_hostname("worker-1.example.com");
+/// let b = HostnameMachineId::from_hostname("worker-1.example.com");
+/// assert_eq!(
+///     a.machine_id::<SnowflakeTwitterId>(),
+///     b.machine_id::<SnowflakeTwitterId>()
+/// );
+/// ```
+///
+/// [`StaticMachineId`]: crate::machine::StaticMachineId
+/// [`LeaseAllocator`]: crate::machine::LeaseAllocator
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HostnameMachineId {
+    hostname: OsString,
+}
+
+impl HostnameMachineId {
+    /// Creates a provider from the host name reported by the operating
+    /// system.
+    pub fn new() -> Self {
+        Self::from_hostname(gethostname::gethostname())
+    }
+
+    /// Creates a provider from an explicit host name.
+    pub fn from_hostname(hostname: impl Into<OsString>) -> Self {
+        Self {
+            hostname: hostname.into(),
+        }
+    }
+
+    /// Returns the host name the machine ID is derived from.
+    pub fn hostname(&self) -> &OsString {
+        &self.hostname
+    }
+}
+
+impl Default for HostnameMachineId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MachineIdProvider for HostnameMachineId {
+    type Err = Error;
+
+    fn machine_id<ID: SnowflakeId>(&self) -> Result<ID::Ty, Self::Err> {
+        if self.hostname.is_empty() {
+            return Err(Error::Unavailable);
+        }
+        let hash = fnv1a(self.hostname.as_encoded_bytes());
+        Ok(reduce_machine_id::<ID>(hash))
+    }
+}
+
+/// A [`MachineIdProvider`] that uses the low bits of a local IP address.
+///
+/// Within a subnet the host part of the address is unique, so as long as the
+/// machine ID field is at least as wide as the host part (e.g. 8 bits for a
+/// `/24`), every host gets a distinct machine ID. Wider subnets wrap around
+/// and may collide.
+///
+/// [`IpMachineId::v4`] and [`IpMachineId::v6`] discover the address used for
+/// outbound traffic by "connecting" an unbound UDP socket; no packets are
+/// sent.
+///
+/// # Example
+/// ```
+/// use std::net::Ipv4Addr;
+///
+/// use ferroid::{
+///     id::SnowflakeTwitterId,
+///     machine::{IpMachineId, MachineIdProvider},
+/// };
+///
+/// let provider = IpMachineId::from_addr(Ipv4Addr::new(10, 0, 3, 17).into());
+/// // Twitter IDs have 10 machine ID bits: 0b11_0001_0001
+/// assert_eq!(provider.machine_id::<SnowflakeTwitterId>(), Ok(785));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IpMachineId {
+    addr: IpAddr,
+}
+
+impl IpMachineId {
+    /// Creates a provider from the host's primary local IPv4 address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no IPv4 route is available.
+    pub fn v4() -> Result<Self, Error> {
+        // TEST-NET-1 (RFC 5737): only used for route selection.
+        Self::discover(
+            SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0),
+            SocketAddr::new(Ipv4Addr::new(192, 0, 2, 1).into(), 9),
+        )
+    }
+
+    /// Creates a provider from the host's primary local IPv6 address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no IPv6 route is available.
+    pub fn v6() -> Result<Self, Error> {
+        // Documentation prefix (RFC 3849): only used for route selection.
+        Self::discover(
+            SocketAddr::new(Ipv6Addr::UNSPECIFIED.into(), 0),
+            SocketAddr::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1).into(), 9),
+        )
+    }
+
+    /// Creates a provider from an explicit address.
+    pub const fn from_addr(addr: IpAddr) -> Self {
+        Self { addr }
+    }
+
+    /// Returns the address the machine ID is derived from.
+    pub const fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    fn discover(bind: SocketAddr, route: SocketAddr) -> Result<Self, Error> {
+        let socket = UdpSocket::bind(bind)?;
+        socket.connect(route)?;
+        let addr = socket.local_addr()?.ip();
+        if addr.is_unspecified() {
+            return Err(Error::Unavailable);
+        }
+        Ok(Self::from_addr(addr))
+    }
+}
+
+impl MachineIdProvider for IpMachineId {
+    type Err = Error;
+
+    fn machine_id<ID: SnowflakeId>(&self) -> Result<ID::Ty, Self::Err> {
+        let low = match self.addr {
+            IpAddr::V4(v4) => u64::from(v4.to_bits()),
+            #[allow(clippy::cast_possible_truncation)]
+            IpAddr::V6(v6) => v6.to_bits() as u64,
+        };
+        Ok(reduce_machine_id::<ID>(low))
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::id::{SnowflakeInstagramId, SnowflakeMastodonId, SnowflakeTwitterId};
+
+    #[test]
+    fn hostname_machine_id_is_stable_and_in_range() {
+        let provider = HostnameMachineId::from_hostname("db-replica-3");
+        let a = provider.machine_id::<SnowflakeTwitterId>().unwrap();
+        let b = provider.machine_id::<SnowflakeTwitterId>().unwrap();
+        assert_eq!(a, b);
+        assert!(a <= SnowflakeTwitterId::max_machine_id());
+
+        let wide = provider.machine_id::<SnowflakeInstagramId>().unwrap();
+        assert!(wide <= SnowflakeInstagramId::max_machine_id());
+    }
+
+    #[test]
+    fn hostname_machine_id_rejects_empty_hostname() {
+        let provider = HostnameMachineId::from_hostname("");
+        assert_eq!(
+            provider.machine_id::<SnowflakeTwitterId>(),
+            Err(Error::Unavailable)
+        );
+    }
+
+    #[test]
+    fn ip_machine_id_uses_low_bits() {
+        let v4 = IpMachineId::from_addr(Ipv4Addr::new(192, 168, 1, 255).into());
+        assert_eq!(v4.machine_id::<SnowflakeTwitterId>(), Ok(0x1ff));
+
+        let v6 = IpMachineId::from_addr("fe80::1:2ff".parse().unwrap());
+        assert_eq!(v6.machine_id::<SnowflakeTwitterId>(), Ok(0x2ff));
+    }
+
+    #[test]
+    fn layouts_without_machine_bits_always_resolve_to_zero() {
+        let v4 = IpMachineId::from_addr(Ipv4Addr::new(10, 0, 0, 7).into());
+        assert_eq!(v4.machine_id::<SnowflakeMastodonId>(), Ok(0));
+        let host = HostnameMachineId::from_hostname("anything");
+        assert_eq!(host.machine_id::<SnowflakeMastodonId>(), Ok(0));
+    }
+}
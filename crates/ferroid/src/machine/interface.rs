@@ -0,0 +1,75 @@
+use core::fmt;
+
+use crate::id::{SnowflakeId, ToU64};
+
+/// A trait for sources that decide which machine ID a Snowflake generator
+/// should use.
+///
+/// Every Snowflake generator is constructed with a raw `machine_id`, and two
+/// generators sharing a machine ID (and epoch) can emit duplicate IDs. A
+/// provider centralizes that decision so it can come from configuration, the
+/// host's identity, or a coordinated allocation.
+///
+/// The machine ID is resolved for a concrete layout so the provider can reject
+/// (or reduce) values that do not fit into [`SnowflakeId::max_machine_id`].
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     id::{SnowflakeId, SnowflakeTwitterId},
+///     machine::{Error, MachineIdProvider},
+/// };
+///
+/// struct FromConfig;
+/// impl MachineIdProvider for FromConfig {
+///     type Err = Error;
+///
+///     fn machine_id<ID: SnowflakeId>(&self) -> Result<ID::Ty, Self::Err> {
+///         Ok(ID::Ty::from(7))
+///     }
+/// }
+///
+/// let machine_id = FromConfig.machine_id::<SnowflakeTwitterId>().unwrap();
+/// assert_eq!(machine_id, 7);
+/// ```
+pub trait MachineIdProvider {
+    /// The error type returned by [`MachineIdProvider::machine_id`].
+    type Err: fmt::Debug;
+
+    /// Returns the machine ID to use for the layout `ID`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the machine ID cannot be determined or does not fit
+    /// into `ID`'s machine ID field.
+    fn machine_id<ID: SnowflakeId>(&self) -> Result<ID::Ty, Self::Err>;
+}
+
+/// Converts `value` into `ID::Ty`, returning `None` if it exceeds
+/// [`SnowflakeId::max_machine_id`].
+pub(crate) fn checked_machine_id<ID: SnowflakeId>(value: u64) -> Option<ID::Ty> {
+    let max = ID::max_machine_id().to_u64();
+    if value > max {
+        return None;
+    }
+    Some(from_u64::<ID>(value))
+}
+
+/// Maps an arbitrary `value` (e.g. a hash or address) onto the range
+/// `0..=max_machine_id()`.
+#[cfg(feature = "machine-id")]
+pub(crate) fn reduce_machine_id<ID: SnowflakeId>(value: u64) -> ID::Ty {
+    let reduced = match ID::max_machine_id().to_u64().checked_add(1) {
+        Some(slots) => value % slots,
+        // The machine ID field is at least 64 bits wide; every value fits.
+        None => value,
+    };
+    from_u64::<ID>(reduced)
+}
+
+fn from_u64<ID: SnowflakeId>(value: u64) -> ID::Ty {
+    value
+        .to_be_bytes()
+        .into_iter()
+        .fold(ID::ZERO, |acc, byte| (acc << 8u32) | ID::Ty::from(byte))
+}
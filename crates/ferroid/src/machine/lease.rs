@@ -0,0 +1,380 @@
+use std::{
+    format,
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    string::String,
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, RecvTimeoutError, Sender},
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use fs4::{FileExt, TryLockError};
+
+use crate::{
+    id::{SnowflakeId, ToU64},
+    machine::{Error, MachineIdProvider, checked_machine_id},
+};
+
+/// Allocates machine IDs by leasing slot files in a shared directory.
+///
+/// Each machine ID `n` is represented by a file named `n.lease`. A process
+/// claims a slot by taking an exclusive OS file lock on it and writing a
+/// heartbeat that expires after the configured TTL. While the returned
+/// [`MachineIdLease`] is alive, a background thread renews the heartbeat every
+/// third of the TTL; dropping the lease clears the heartbeat and releases the
+/// lock.
+///
+/// A slot is considered free when its lock can be taken **and** it has no
+/// unexpired heartbeat. The OS lock is released automatically if the holder
+/// dies, while the heartbeat protects holders on filesystems where advisory
+/// locks are not enforced. As a consequence, a slot whose holder crashed may
+/// stay unavailable until its last heartbeat expires.
+///
+/// Slot files are never deleted: unlinking a locked file would let a second
+/// process lock a fresh file at the same path while the first still holds the
+/// old one.
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     generator::BasicSnowflakeGenerator,
+///     id::SnowflakeTwitterId,
+///     machine::{LeaseAllocator, MachineIdProvider},
+///     time::{MonotonicClock, TWITTER_EPOCH},
+/// };
+///
+/// let dir = std::env::temp_dir().join(format!("ferroid-doc-lease-{}", std::process::id()));
+/// let allocator = LeaseAllocator::new(&dir);
+///
+/// // Keep the lease alive for as long as the generator is in use.
+/// let lease = allocator.acquire::<SnowflakeTwitterId>().unwrap();
+/// let machine_id = lease.machine_id::<SnowflakeTwitterId>().unwrap();
+///
+/// let generator = BasicSnowflakeGenerator::<SnowflakeTwitterId, _>::new(
+///     machine_id,
+///     MonotonicClock::<1>::with_epoch(TWITTER_EPOCH),
+/// );
+/// let id = generator.next_id(|_| std::thread::yield_now());
+/// assert_eq!(id.machine_id(), machine_id);
+/// # drop(lease);
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LeaseAllocator {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl LeaseAllocator {
+    /// The lease TTL used by [`LeaseAllocator::new`].
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(30);
+
+    /// Creates an allocator that leases slots in `dir` with
+    /// [`LeaseAllocator::DEFAULT_TTL`].
+    ///
+    /// The directory is created on the first call to
+    /// [`LeaseAllocator::acquire`] if it does not exist.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self::with_ttl(dir, Self::DEFAULT_TTL)
+    }
+
+    /// Creates an allocator that leases slots in `dir` with a custom TTL.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ttl` is zero.
+    pub fn with_ttl(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        assert!(!ttl.is_zero(), "lease TTL must be non-zero");
+        Self {
+            dir: dir.into(),
+            ttl,
+        }
+    }
+
+    /// Returns the directory holding the slot files.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the lease TTL.
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Claims the lowest free machine ID in `0..=ID::max_machine_id()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Exhausted`] if every slot is held, or [`Error::Io`] if
+    /// the directory or a slot file cannot be accessed or the renewal thread
+    /// cannot be spawned. A slot is released again if its renewal thread fails
+    /// to start.
+    pub fn acquire<ID: SnowflakeId>(&self) -> Result<MachineIdLease, Error> {
+        fs::create_dir_all(&self.dir)?;
+        let max = ID::max_machine_id().to_u64();
+        for machine_id in 0..=max {
+            if let Some(lease) = self.try_claim(machine_id)? {
+                return Ok(lease);
+            }
+        }
+        Err(Error::Exhausted)
+    }
+
+    fn try_claim(&self, machine_id: u64) -> Result<Option<MachineIdLease>, Error> {
+        let path = self.dir.join(format!("{machine_id}.lease"));
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)?;
+
+        // Fully qualified: newer toolchains add an inherent `File::try_lock`.
+        match FileExt::try_lock(&file) {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => return Ok(None),
+            Err(TryLockError::Error(e)) => return Err(e.into()),
+        }
+
+        let mut heartbeat = String::new();
+        file.read_to_string(&mut heartbeat)?;
+        if parse_expiry(&heartbeat).is_some_and(|expires| expires > unix_millis()) {
+            FileExt::unlock(&file)?;
+            return Ok(None);
+        }
+
+        write_heartbeat(&mut file, self.ttl)?;
+        Ok(Some(MachineIdLease::start(machine_id, file, self.ttl)?))
+    }
+}
+
+/// A machine ID claimed through a [`LeaseAllocator`].
+///
+/// The lease is renewed in the background and released on drop. It implements
+/// [`MachineIdProvider`] so it can be passed wherever a provider is expected;
+/// resolving fails with [`Error::OutOfRange`] if the leased ID does not fit the
+/// requested layout.
+pub struct MachineIdLease {
+    machine_id: u64,
+    ttl: Duration,
+    file: Arc<Mutex<File>>,
+    renewer: Option<(Sender<()>, JoinHandle<()>)>,
+}
+
+impl MachineIdLease {
+    fn start(machine_id: u64, file: File, ttl: Duration) -> io::Result<Self> {
+        let file = Arc::new(Mutex::new(file));
+        let (stop, stopped) = mpsc::channel::<()>();
+        let renewed = Arc::clone(&file);
+        let spawned = thread::Builder::new()
+            .name(format!("ferroid-lease-{machine_id}"))
+            .spawn(move || {
+                // Exits once the sender is dropped.
+                while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(ttl / 3) {
+                    if let Ok(mut file) = renewed.lock() {
+                        // A failed renewal is retried on the next interval.
+                        let _ = write_heartbeat(&mut file, ttl);
+                    }
+                }
+            });
+        let handle = match spawned {
+            Ok(handle) => handle,
+            Err(e) => {
+                // Nothing will renew the heartbeat, so give the slot back.
+                if let Ok(file) = file.lock() {
+                    release(&file);
+                }
+                return Err(e);
+            }
+        };
+
+        Ok(Self {
+            machine_id,
+            ttl,
+            file,
+            renewer: Some((stop, handle)),
+        })
+    }
+
+    /// Returns the leased machine ID.
+    pub const fn raw_machine_id(&self) -> u64 {
+        self.machine_id
+    }
+
+    /// Extends the lease by its TTL.
+    ///
+    /// Renewal happens automatically in the background; call this to renew
+    /// immediately, e.g. after the process was suspended.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the heartbeat cannot be written.
+    pub fn renew(&self) -> Result<(), Error> {
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| Error::Io(io::ErrorKind::Other))?;
+        write_heartbeat(&mut file, self.ttl)?;
+        Ok(())
+    }
+}
+
+impl MachineIdProvider for MachineIdLease {
+    type Err = Error;
+
+    fn machine_id<ID: SnowflakeId>(&self) -> Result<ID::Ty, Self::Err> {
+        checked_machine_id::<ID>(self.machine_id).ok_or(Error::OutOfRange)
+    }
+}
+
+impl core::fmt::Debug for MachineIdLease {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MachineIdLease")
+            .field("machine_id", &self.machine_id)
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for MachineIdLease {
+    fn drop(&mut self) {
+        if let Some((stop, handle)) = self.renewer.take() {
+            drop(stop);
+            let _ = handle.join();
+        }
+        if let Ok(file) = self.file.lock() {
+            release(&file);
+        }
+    }
+}
+
+/// Clears the heartbeat and unlocks a claimed slot file.
+///
+/// The file itself is kept; see [`LeaseAllocator`] for why slots are never
+/// deleted.
+fn release(file: &File) {
+    // Clear the heartbeat first so the slot is free as soon as the lock is
+    // released.
+    let _ = file.set_len(0);
+    let _ = FileExt::unlock(file);
+}
+
+fn write_heartbeat(file: &mut File, ttl: Duration) -> io::Result<()> {
+    let ttl_millis = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+    let expires = unix_millis().saturating_add(ttl_millis);
+    let heartbeat = format!("pid={} expires={expires}\n", std::process::id());
+    // Overwrite in place before trimming so concurrent readers never observe
+    // an empty (and therefore reclaimable) heartbeat.
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(heartbeat.as_bytes())?;
+    file.set_len(heartbeat.len() as u64)?;
+    file.sync_data()
+}
+
+fn parse_expiry(heartbeat: &str) -> Option<u64> {
+    heartbeat
+        .split_whitespace()
+        .find_map(|field| field.strip_prefix("expires="))
+        .and_then(|expires| expires.parse().ok())
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::id::{SnowflakeMastodonId, SnowflakeTwitterId};
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "ferroid-{name}-{}-{}",
+                std::process::id(),
+                unix_millis()
+            ));
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn leases_are_distinct_and_released_on_drop() {
+        let dir = TempDir::new("lease-distinct");
+        let allocator = LeaseAllocator::new(&dir.0);
+
+        let a = allocator.acquire::<SnowflakeTwitterId>().unwrap();
+        let b = allocator.acquire::<SnowflakeTwitterId>().unwrap();
+        assert_eq!(a.raw_machine_id(), 0);
+        assert_eq!(b.raw_machine_id(), 1);
+
+        drop(a);
+        let c = allocator.acquire::<SnowflakeTwitterId>().unwrap();
+        assert_eq!(c.raw_machine_id(), 0);
+        assert_eq!(c.machine_id::<SnowflakeTwitterId>(), Ok(0));
+    }
+
+    #[test]
+    fn acquire_fails_when_every_slot_is_leased() {
+        let dir = TempDir::new("lease-exhausted");
+        let allocator = LeaseAllocator::new(&dir.0);
+
+        let _only = allocator.acquire::<SnowflakeMastodonId>().unwrap();
+        assert_eq!(
+            allocator.acquire::<SnowflakeMastodonId>().unwrap_err(),
+            Error::Exhausted
+        );
+    }
+
+    #[test]
+    fn unexpired_heartbeat_blocks_slot_until_it_expires() {
+        let dir = TempDir::new("lease-heartbeat");
+        let allocator = LeaseAllocator::with_ttl(&dir.0, Duration::from_millis(50));
+        fs::create_dir_all(&dir.0).unwrap();
+
+        // A holder on a filesystem without working locks.
+        let expires = unix_millis() + 60_000;
+        fs::write(dir.0.join("0.lease"), format!("pid=1 expires={expires}\n")).unwrap();
+        let lease = allocator.acquire::<SnowflakeTwitterId>().unwrap();
+        assert_eq!(lease.raw_machine_id(), 1);
+
+        // An expired heartbeat is reclaimed.
+        fs::write(dir.0.join("0.lease"), "pid=1 expires=0\n").unwrap();
+        let lease = allocator.acquire::<SnowflakeTwitterId>().unwrap();
+        assert_eq!(lease.raw_machine_id(), 0);
+    }
+
+    #[test]
+    fn lease_is_renewed_while_held() {
+        let dir = TempDir::new("lease-renew");
+        let allocator = LeaseAllocator::with_ttl(&dir.0, Duration::from_millis(30));
+        let lease = allocator.acquire::<SnowflakeTwitterId>().unwrap();
+
+        let first = parse_expiry(&fs::read_to_string(dir.0.join("0.lease")).unwrap()).unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let renewed = parse_expiry(&fs::read_to_string(dir.0.join("0.lease")).unwrap()).unwrap();
+        assert!(renewed > first);
+
+        lease.renew().unwrap();
+        drop(lease);
+        assert!(
+            fs::read_to_string(dir.0.join("0.lease"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
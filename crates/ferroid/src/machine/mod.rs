@@ -0,0 +1,20 @@
+mod error;
+mod fixed;
+#[cfg(feature = "machine-id")]
+mod host;
+mod interface;
+#[cfg(all(feature = "machine-id", any(unix, windows)))]
+mod lease;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "snowflake")))]
+pub use error::*;
+#[cfg_attr(docsrs, doc(cfg(feature = "snowflake")))]
+pub use fixed::*;
+#[cfg_attr(docsrs, doc(cfg(feature = "machine-id")))]
+#[cfg(feature = "machine-id")]
+pub use host::*;
+#[cfg_attr(docsrs, doc(cfg(feature = "snowflake")))]
+pub use interface::*;
+#[cfg_attr(docsrs, doc(cfg(all(feature = "machine-id", any(unix, windows)))))]
+#[cfg(all(feature = "machine-id", any(unix, windows)))]
+pub use lease::*;
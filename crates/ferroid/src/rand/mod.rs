@@ -1,7 +1,19 @@
 mod interface;
+#[cfg(feature = "os-rand")]
+mod os_random;
+#[cfg(feature = "std")]
+mod std_random;
 #[cfg(feature = "std")]
 mod thread_random;
+#[cfg(target_has_atomic = "64")]
+mod wyrand;
 
 pub use interface::*;
+#[cfg(feature = "os-rand")]
+pub use os_random::*;
+#[cfg(feature = "std")]
+pub use std_random::*;
 #[cfg(feature = "std")]
 pub use thread_random::*;
+#[cfg(target_has_atomic = "64")]
+pub use wyrand::*;
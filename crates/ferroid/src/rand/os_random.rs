@@ -0,0 +1,48 @@
+use crate::rand::RandSource;
+
+/// A `RandSource` that reads directly from the operating system's secure
+/// random number generator via [`getrandom`].
+///
+/// Works in `no_std` environments on every target [`getrandom`] supports
+/// (including custom backends registered by the application). Output is
+/// cryptographically secure, so IDs are unguessable, and there is no shared
+/// state to seed or contend on. The cost is a system call (or equivalent) per
+/// ID, which makes it noticeably slower than [`ThreadRandom`] or [`WyRand`].
+///
+/// # Panics
+///
+/// [`RandSource::rand`] cannot report errors, so it panics if the OS fails to
+/// provide randomness. This only happens on misconfigured or exotic targets.
+///
+/// [`getrandom`]: https://docs.rs/getrandom
+/// [`ThreadRandom`]: crate::rand::ThreadRandom
+/// [`WyRand`]: crate::rand::WyRand
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsRandom;
+
+impl RandSource<u64> for OsRandom {
+    fn rand(&self) -> u64 {
+        getrandom::u64().expect("OS random number generator failed")
+    }
+}
+
+impl RandSource<u128> for OsRandom {
+    fn rand(&self) -> u128 {
+        let mut bytes = [0; 16];
+        getrandom::fill(&mut bytes).expect("OS random number generator failed");
+        u128::from_ne_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_are_not_constant() {
+        let rng = OsRandom;
+        let a: u128 = rng.rand();
+        let b: u128 = rng.rand();
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,100 @@
+use portable_atomic::{AtomicU64, Ordering};
+
+use crate::rand::RandSource;
+
+/// The wyrand state increment.
+const WY_P0: u64 = 0xA076_1D64_78BD_642F;
+/// The wyrand mixing constant.
+const WY_P1: u64 = 0xE703_7ED1_A0B4_28DB;
+
+/// A fast, non-cryptographic `RandSource` based on wyrand.
+///
+/// Each call is a single atomic add plus a multiply, so it is `no_std`
+/// friendly, lock-free, and much faster than the cryptographic sources. It is
+/// intended for bulk synthetic data, benchmarks, and load tests.
+///
+/// The trade-off is predictability: observing a few outputs is enough to
+/// recover the state and predict (or forge) future IDs. Its 64-bit state also
+/// means a `u128` draw carries at most 64 bits of entropy, so the collision
+/// probability between independently seeded instances is far higher than the
+/// full 80-bit ULID random component suggests. Do not use it for IDs exposed to
+/// untrusted parties.
+///
+/// Instances with the same seed produce the same sequence when driven from a
+/// single thread.
+///
+/// # Example
+/// ```
+/// use ferroid::rand::{RandSource, WyRand};
+///
+/// let rng = WyRand::new(42);
+/// let a: u64 = rng.rand();
+/// let b: u64 = rng.rand();
+/// assert_ne!(a, b);
+/// ```
+#[derive(Debug)]
+pub struct WyRand {
+    state: AtomicU64,
+}
+
+impl WyRand {
+    /// Creates a generator with the given seed.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    fn next_u64(&self) -> u64 {
+        let s = self
+            .state
+            .fetch_add(WY_P0, Ordering::Relaxed)
+            .wrapping_add(WY_P0);
+        let t = u128::from(s) * u128::from(s ^ WY_P1);
+        #[allow(clippy::cast_possible_truncation)]
+        let value = (t >> 64) as u64 ^ t as u64;
+        value
+    }
+}
+
+impl Clone for WyRand {
+    /// Returns a generator that continues from this one's current position.
+    fn clone(&self) -> Self {
+        Self::new(self.state.load(Ordering::Relaxed))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for WyRand {
+    /// Creates a generator seeded from the thread-local RNG.
+    fn default() -> Self {
+        Self::new(rand::random())
+    }
+}
+
+impl RandSource<u64> for WyRand {
+    fn rand(&self) -> u64 {
+        self.next_u64()
+    }
+}
+
+impl RandSource<u128> for WyRand {
+    fn rand(&self) -> u128 {
+        (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let a = WyRand::new(7);
+        let b = WyRand::new(7);
+        for _ in 0..16 {
+            assert_eq!(RandSource::<u128>::rand(&a), RandSource::<u128>::rand(&b));
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use alloc::collections::BTreeMap;
+use core::fmt::Debug;
+
+/// Asserts that every item is strictly greater than the one before it.
+///
+/// Use this to check that a generator never repeats or reorders IDs across a
+/// simulated clock change.
+///
+/// # Panics
+///
+/// Panics, naming the offending positions, if any item is less than or equal
+/// to its predecessor.
+///
+/// # Example
+/// ```
+/// use ferroid::testing::assert_strictly_monotonic;
+///
+/// assert_strictly_monotonic([1, 2, 5]);
+/// ```
+#[track_caller]
+pub fn assert_strictly_monotonic<I>(ids: I)
+where
+    I: IntoIterator,
+    I::Item: PartialOrd + Debug,
+{
+    let mut iter = ids.into_iter().enumerate();
+    let Some((_, mut prev)) = iter.next() else {
+        return;
+    };
+    for (index, id) in iter {
+        assert!(
+            id > prev,
+            "ids are not strictly increasing: ids[{}] = {prev:?} is followed by ids[{index}] = {id:?}",
+            index - 1,
+        );
+        prev = id;
+    }
+}
+
+/// Asserts that no item appears more than once.
+///
+/// Unlike [`assert_strictly_monotonic`], the order of the items does not
+/// matter, so this is suited to IDs collected from several threads.
+///
+/// # Panics
+///
+/// Panics, naming both positions, if any item is repeated.
+///
+/// # Example
+/// ```
+/// use ferroid::testing::assert_unique;
+///
+/// assert_unique([3, 1, 2]);
+/// ```
+#[track_caller]
+pub fn assert_unique<I>(ids: I)
+where
+    I: IntoIterator,
+    I::Item: Ord + Debug,
+{
+    let mut seen = BTreeMap::new();
+    for (index, id) in ids.into_iter().enumerate() {
+        if let Some(first) = seen.get(&id) {
+            panic!("duplicate id {id:?} at ids[{first}] and ids[{index}]");
+        }
+        seen.insert(id, index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "ids[1] = 2 is followed by ids[2] = 2")]
+    fn strictly_monotonic_rejects_repeats() {
+        assert_strictly_monotonic([1, 2, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate id 1 at ids[0] and ids[2]")]
+    fn unique_reports_both_positions() {
+        assert_unique([1, 3, 1]);
+    }
+}
@@ -0,0 +1,97 @@
+use alloc::sync::Arc;
+
+use portable_atomic::{AtomicU64, Ordering};
+
+use crate::time::TimeSource;
+
+/// A [`TimeSource`] that only changes when explicitly moved.
+///
+/// Clones share the same underlying time, so a test can hand one clone to a
+/// generator and keep another to drive it. The clock can be advanced, rewound
+/// (to simulate a wall clock stepping backwards), or set to any value.
+///
+/// One unit is one millisecond.
+///
+/// # Example
+/// ```
+/// use ferroid::{testing::ManualClock, time::TimeSource};
+///
+/// let clock = ManualClock::new(10);
+/// let handle = clock.clone();
+///
+/// handle.advance(5);
+/// assert_eq!(TimeSource::<u64>::current_millis(&clock), 15);
+///
+/// handle.rewind(20);
+/// assert_eq!(TimeSource::<u64>::current_millis(&clock), 0);
+///
+/// handle.set(1_000);
+/// assert_eq!(clock.now(), 1_000);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock {
+    now: Arc<AtomicU64>,
+}
+
+impl ManualClock {
+    /// Creates a clock that reads `start` until it is moved.
+    #[must_use]
+    pub fn new(start: u64) -> Self {
+        Self {
+            now: Arc::new(AtomicU64::new(start)),
+        }
+    }
+
+    /// Returns the current time.
+    #[must_use]
+    pub fn now(&self) -> u64 {
+        self.now.load(Ordering::Acquire)
+    }
+
+    /// Jumps to `now`, forwards or backwards.
+    pub fn set(&self, now: u64) {
+        self.now.store(now, Ordering::Release);
+    }
+
+    /// Moves the clock forward by `by`, saturating at [`u64::MAX`].
+    pub fn advance(&self, by: u64) {
+        // `fetch_update` cannot fail when the closure always returns `Some`.
+        let _ = self
+            .now
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |now| {
+                Some(now.saturating_add(by))
+            });
+    }
+
+    /// Moves the clock backward by `by`, saturating at zero.
+    pub fn rewind(&self, by: u64) {
+        let _ = self
+            .now
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |now| {
+                Some(now.saturating_sub(by))
+            });
+    }
+}
+
+impl TimeSource<u32> for ManualClock {
+    /// Returns the current time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current time does not fit in a `u32`.
+    fn current_millis(&self) -> u32 {
+        u32::try_from(self.now()).expect("ManualClock time exceeds u32::MAX")
+    }
+}
+
+impl TimeSource<u64> for ManualClock {
+    fn current_millis(&self) -> u64 {
+        self.now()
+    }
+}
+
+impl TimeSource<u128> for ManualClock {
+    fn current_millis(&self) -> u128 {
+        u128::from(self.now())
+    }
+}
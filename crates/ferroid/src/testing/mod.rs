@@ -0,0 +1,41 @@
+//! Deterministic building blocks for testing code that generates IDs.
+//!
+//! Generators are only as predictable as the [`TimeSource`] and [`RandSource`]
+//! they are built with. This module provides a [`ManualClock`] that only moves
+//! when told to, a reproducible [`SeededRandom`], and assertion helpers for
+//! checking generator output.
+//!
+//! # Example
+//! ```
+//! use ferroid::{
+//!     generator::{BasicSnowflakeGenerator, Poll},
+//!     id::SnowflakeTwitterId,
+//!     testing::{ManualClock, assert_strictly_monotonic, assert_unique},
+//! };
+//!
+//! let clock = ManualClock::new(100);
+//! let generator = BasicSnowflakeGenerator::<SnowflakeTwitterId, _>::new(0, clock.clone());
+//!
+//! let first = generator.next_id(|_| unreachable!());
+//!
+//! // Simulate the wall clock stepping backwards by 5ms.
+//! clock.rewind(5);
+//! assert_eq!(generator.poll_id(), Poll::Pending { yield_for: 5 });
+//!
+//! clock.advance(10);
+//! let second = generator.next_id(|_| unreachable!());
+//!
+//! assert_strictly_monotonic([first, second]);
+//! assert_unique([first, second]);
+//! ```
+//!
+//! [`TimeSource`]: crate::time::TimeSource
+//! [`RandSource`]: crate::rand::RandSource
+
+mod assertions;
+mod manual_clock;
+mod seeded_random;
+
+pub use assertions::*;
+pub use manual_clock::*;
+pub use seeded_random::*;
@@ -0,0 +1,107 @@
+use portable_atomic::{AtomicU64, Ordering};
+
+use crate::rand::RandSource;
+
+/// The SplitMix64 increment (the golden ratio in 64-bit fixed point).
+const GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// A reproducible [`RandSource`] seeded with a fixed value.
+///
+/// Uses SplitMix64, so the same seed always yields the same sequence. The state
+/// is a single atomic counter: calls from multiple threads never repeat a value,
+/// but which thread receives which value depends on scheduling. For fully
+/// reproducible output, drive it from one thread.
+///
+/// This is **not** cryptographically secure and must not be used outside of
+/// tests.
+///
+/// # Example
+/// ```
+/// use ferroid::{rand::RandSource, testing::SeededRandom};
+///
+/// let a = SeededRandom::new(42);
+/// let b = SeededRandom::new(42);
+/// let first: u64 = a.rand();
+/// assert_eq!(first, b.rand());
+/// ```
+#[derive(Debug, Default)]
+pub struct SeededRandom {
+    state: AtomicU64,
+}
+
+impl SeededRandom {
+    /// Creates a source that produces the sequence for `seed`.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    fn next_u64(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(GAMMA, Ordering::Relaxed)
+            .wrapping_add(GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Clone for SeededRandom {
+    /// Returns a source that continues from this one's current position.
+    fn clone(&self) -> Self {
+        Self::new(self.state.load(Ordering::Relaxed))
+    }
+}
+
+impl RandSource<u32> for SeededRandom {
+    fn rand(&self) -> u32 {
+        #[allow(clippy::cast_possible_truncation)]
+        let value = (self.next_u64() >> 32) as u32;
+        value
+    }
+}
+
+impl RandSource<u64> for SeededRandom {
+    fn rand(&self) -> u64 {
+        self.next_u64()
+    }
+}
+
+impl RandSource<u128> for SeededRandom {
+    fn rand(&self) -> u128 {
+        (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_reference_splitmix64_output() {
+        // Reference values for seed 0 from the SplitMix64 paper's C code.
+        let rng = SeededRandom::new(0);
+        let values: [u64; 3] = [rng.rand(), rng.rand(), rng.rand()];
+        assert_eq!(
+            values,
+            [
+                0xE220_A839_7B1D_CDAF,
+                0x6E78_9E6A_A1B9_65F4,
+                0x06C4_5D18_8009_454F
+            ]
+        );
+    }
+
+    #[test]
+    fn clone_continues_from_the_same_position() {
+        let rng = SeededRandom::new(7);
+        let _: u64 = rng.rand();
+        let copy = rng.clone();
+        let a: u128 = rng.rand();
+        let b: u128 = copy.rand();
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,121 @@
+use core::time::Duration;
+use std::time::SystemTime;
+
+use crate::time::{TimeSource, UNIX_EPOCH};
+
+/// Reads `CLOCK_MONOTONIC_COARSE` in milliseconds.
+fn coarse_now_millis() -> u64 {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `ts` is a valid, writable `timespec`, and
+    // `CLOCK_MONOTONIC_COARSE` is supported by every Linux kernel since 2.6.32.
+    let rc = unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC_COARSE, &raw mut ts) };
+    debug_assert_eq!(rc, 0, "clock_gettime(CLOCK_MONOTONIC_COARSE) failed");
+
+    #[allow(clippy::cast_sign_loss)]
+    let (secs, nanos) = (ts.tv_sec as u64, ts.tv_nsec as u64);
+    secs.saturating_mul(1_000).saturating_add(nanos / 1_000_000)
+}
+
+/// A monotonic time source backed by Linux's `CLOCK_MONOTONIC_COARSE`.
+///
+/// The coarse clock is served from the vDSO without touching the hardware
+/// timer, making it the cheapest way to read monotonic time on Linux. Its
+/// resolution is the kernel tick (typically 1–4 ms), so several consecutive
+/// reads return the same value; generators simply fill the sequence (or
+/// random component) for that tick and then wait for the next one.
+///
+/// Like [`InstantClock`], the wall clock is sampled once at construction to
+/// anchor the epoch and no background thread is used.
+///
+/// `N` controls the number of real milliseconds represented by one returned
+/// time unit.
+///
+/// # Example
+/// ```
+/// use ferroid::time::{CoarseMonotonicClock, TimeSource, UNIX_EPOCH};
+///
+/// let clock = CoarseMonotonicClock::<1>::with_epoch(UNIX_EPOCH);
+/// let a: u64 = clock.current_millis();
+/// let b: u64 = clock.current_millis();
+/// assert!(b >= a);
+/// ```
+///
+/// [`InstantClock`]: crate::time::InstantClock
+#[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
+#[derive(Clone, Copy, Debug)]
+pub struct CoarseMonotonicClock<const N: u64 = 1> {
+    start: u64,        // coarse monotonic milliseconds at construction
+    epoch_offset: u64, // in milliseconds
+}
+
+impl Default for CoarseMonotonicClock<1> {
+    /// Constructs a coarse clock aligned to the default [`UNIX_EPOCH`].
+    fn default() -> Self {
+        Self::with_epoch(UNIX_EPOCH)
+    }
+}
+
+impl<const N: u64> CoarseMonotonicClock<N> {
+    const ASSERT_VALID_GRANULARITY: () = assert!(
+        N > 0,
+        "CoarseMonotonicClock granularity must be greater than zero"
+    );
+    pub const GRANULARITY_MILLIS: u64 = N;
+
+    /// Constructs a coarse clock using `epoch` (a [`Duration`] since
+    /// 1970-01-01 UTC) as the origin.
+    #[must_use]
+    pub fn with_epoch(epoch: Duration) -> Self {
+        let () = Self::ASSERT_VALID_GRANULARITY;
+        let start = coarse_now_millis();
+        let system_now = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let epoch_offset = system_now.saturating_sub(epoch).as_millis() as u64;
+
+        Self {
+            start,
+            epoch_offset,
+        }
+    }
+}
+
+impl<const N: u64> TimeSource<u64> for CoarseMonotonicClock<N> {
+    const GRANULARITY_MILLIS: u64 = Self::GRANULARITY_MILLIS;
+
+    /// Returns the number of `N`-millisecond units since the configured epoch.
+    fn current_millis(&self) -> u64 {
+        let () = Self::ASSERT_VALID_GRANULARITY;
+        let elapsed = coarse_now_millis().saturating_sub(self.start);
+        (self.epoch_offset + elapsed) / N
+    }
+}
+
+impl<const N: u64> TimeSource<u128> for CoarseMonotonicClock<N> {
+    const GRANULARITY_MILLIS: u64 = Self::GRANULARITY_MILLIS;
+
+    /// Returns the number of `N`-millisecond units since the configured epoch.
+    fn current_millis(&self) -> u128 {
+        u128::from(<Self as TimeSource<u64>>::current_millis(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advances_with_elapsed_time() {
+        let clock = CoarseMonotonicClock::default();
+        let before = <CoarseMonotonicClock as TimeSource<u64>>::current_millis(&clock);
+        std::thread::sleep(Duration::from_millis(20));
+        let after = <CoarseMonotonicClock as TimeSource<u64>>::current_millis(&clock);
+        // Allow for the coarse clock's tick resolution.
+        assert!(after >= before + 10, "{before} -> {after}");
+    }
+}
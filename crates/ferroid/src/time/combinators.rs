@@ -0,0 +1,286 @@
+#[cfg(target_has_atomic = "64")]
+use portable_atomic::{AtomicU64, Ordering};
+
+use crate::{id::ToU64, time::TimeSource};
+
+/// Integer types the combinators in this module can read and produce.
+///
+/// Combinators compute in `u64` and convert back, so both directions saturate:
+/// `u128` readings above `u64::MAX` are clamped on the way in (see [`ToU64`]),
+/// and `u32` results above `u32::MAX` are clamped on the way out. Millisecond
+/// timestamps stay far below either bound in practice.
+pub trait TimeUnit: Copy + Ord + ToU64 {
+    /// Converts from `u64`, saturating at `Self::MAX`.
+    fn from_u64(value: u64) -> Self;
+}
+
+impl TimeUnit for u32 {
+    fn from_u64(value: u64) -> Self {
+        Self::try_from(value).unwrap_or(Self::MAX)
+    }
+}
+
+impl TimeUnit for u64 {
+    fn from_u64(value: u64) -> Self {
+        value
+    }
+}
+
+impl TimeUnit for u128 {
+    fn from_u64(value: u64) -> Self {
+        Self::from(value)
+    }
+}
+
+/// Shifts another time source by a fixed, signed number of its own units.
+///
+/// Useful for simulating cross-node clock skew in tests, or for correcting a
+/// known offset. Results saturate at zero and at the unit's maximum.
+///
+/// # Example
+/// ```
+/// use ferroid::time::{OffsetClock, TimeSource};
+///
+/// let ahead = OffsetClock::new(|| 1_000_u64, 250);
+/// let behind = OffsetClock::new(|| 1_000_u64, -250);
+/// assert_eq!(ahead.current_millis(), 1_250);
+/// assert_eq!(behind.current_millis(), 750);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct OffsetClock<S> {
+    inner: S,
+    offset: i64,
+}
+
+impl<S> OffsetClock<S> {
+    /// Wraps `inner`, adding `offset` units to every reading.
+    pub const fn new(inner: S, offset: i64) -> Self {
+        Self { inner, offset }
+    }
+
+    /// Returns the applied offset.
+    pub const fn offset(&self) -> i64 {
+        self.offset
+    }
+
+    /// Returns the wrapped time source.
+    pub const fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<T, S> TimeSource<T> for OffsetClock<S>
+where
+    T: TimeUnit,
+    S: TimeSource<T>,
+{
+    const GRANULARITY_MILLIS: u64 = S::GRANULARITY_MILLIS;
+    const GRANULARITY_NANOS: u64 = S::GRANULARITY_NANOS;
+
+    fn current_millis(&self) -> T {
+        let now = self.inner.current_millis().to_u64();
+        T::from_u64(now.saturating_add_signed(self.offset))
+    }
+}
+
+/// Groups another time source's readings into `N`-unit quanta.
+///
+/// This generalizes the `N` parameter of [`MonotonicClock`] to any source:
+/// `Quantized<_, 8>` over a millisecond clock returns 8-millisecond ticks, and
+/// the reported granularity is scaled to match.
+///
+/// # Example
+/// ```
+/// use ferroid::time::{Quantized, TimeSource};
+///
+/// let clock = Quantized::<_, 8>::new(|| 100_u64);
+/// assert_eq!(clock.current_millis(), 12);
+/// assert_eq!(<Quantized<fn() -> u64, 8> as TimeSource<u64>>::GRANULARITY_MILLIS, 8);
+/// ```
+///
+/// The quantum must be non-zero:
+///
+/// ```compile_fail
+/// use ferroid::time::Quantized;
+///
+/// let _ = Quantized::<_, 0>::new(|| 0_u64);
+/// ```
+///
+/// [`MonotonicClock`]: crate::time::MonotonicClock
+#[derive(Clone, Copy, Debug)]
+pub struct Quantized<S, const N: u64> {
+    inner: S,
+}
+
+impl<S, const N: u64> Quantized<S, N> {
+    const ASSERT_VALID_QUANTUM: () = assert!(N > 0, "Quantized quantum must be greater than zero");
+
+    /// Wraps `inner`, dividing every reading by `N`.
+    pub const fn new(inner: S) -> Self {
+        let () = Self::ASSERT_VALID_QUANTUM;
+        Self { inner }
+    }
+
+    /// Returns the wrapped time source.
+    pub const fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<T, S, const N: u64> TimeSource<T> for Quantized<S, N>
+where
+    T: TimeUnit,
+    S: TimeSource<T>,
+{
+    const GRANULARITY_MILLIS: u64 = S::GRANULARITY_MILLIS.saturating_mul(N);
+    const GRANULARITY_NANOS: u64 = S::GRANULARITY_NANOS.saturating_mul(N);
+
+    fn current_millis(&self) -> T {
+        T::from_u64(self.inner.current_millis().to_u64() / N)
+    }
+}
+
+/// Prevents another time source from ever going backwards.
+///
+/// The largest reading seen so far is kept in an atomic. When the wrapped
+/// source regresses (such as a wall clock stepped back by NTP), that value is
+/// returned instead until the source catches up. Wrapping [`SystemClock`]
+/// gives a wall-accurate clock that never regresses.
+///
+/// Clones start with their own high-water mark.
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     testing::ManualClock,
+///     time::{NeverBackwards, TimeSource},
+/// };
+///
+/// let source = ManualClock::new(100);
+/// let clock = NeverBackwards::new(source.clone());
+/// assert_eq!(TimeSource::<u64>::current_millis(&clock), 100);
+///
+/// source.rewind(40);
+/// assert_eq!(TimeSource::<u64>::current_millis(&clock), 100);
+///
+/// source.set(105);
+/// assert_eq!(TimeSource::<u64>::current_millis(&clock), 105);
+/// ```
+///
+/// [`SystemClock`]: crate::time::SystemClock
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug)]
+pub struct NeverBackwards<S> {
+    inner: S,
+    last: AtomicU64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<S> NeverBackwards<S> {
+    /// Wraps `inner`.
+    pub const fn new(inner: S) -> Self {
+        Self {
+            inner,
+            last: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the wrapped time source.
+    pub const fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<S: Clone> Clone for NeverBackwards<S> {
+    fn clone(&self) -> Self {
+        Self::new(self.inner.clone())
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<T, S> TimeSource<T> for NeverBackwards<S>
+where
+    T: TimeUnit,
+    S: TimeSource<T>,
+{
+    const GRANULARITY_MILLIS: u64 = S::GRANULARITY_MILLIS;
+    const GRANULARITY_NANOS: u64 = S::GRANULARITY_NANOS;
+
+    fn current_millis(&self) -> T {
+        let now = self.inner.current_millis();
+        let raw = now.to_u64();
+        let last = self.last.fetch_max(raw, Ordering::Relaxed);
+        if last > raw { T::from_u64(last) } else { now }
+    }
+}
+
+/// Returns the later of two time sources' readings.
+///
+/// Combining a monotonic clock with a wall clock, for example, follows the
+/// wall clock forward while never dropping below the monotonic reading. Both
+/// sources must report the same granularity; mismatches fail to compile.
+///
+/// # Example
+/// ```
+/// use ferroid::time::{MaxOf, TimeSource};
+///
+/// let clock = MaxOf(|| 10_u64, || 20_u64);
+/// assert_eq!(clock.current_millis(), 20);
+/// ```
+///
+/// ```compile_fail
+/// use ferroid::time::{FnClock, MaxOf, TimeSource};
+///
+/// let clock = MaxOf(FnClock::<_, 1>::new(|| 0_u64), FnClock::<_, 8>::new(|| 0_u64));
+/// let _: u64 = clock.current_millis();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MaxOf<A, B>(pub A, pub B);
+
+impl<T, A, B> TimeSource<T> for MaxOf<A, B>
+where
+    T: Ord,
+    A: TimeSource<T>,
+    B: TimeSource<T>,
+{
+    const GRANULARITY_MILLIS: u64 = A::GRANULARITY_MILLIS;
+    const GRANULARITY_NANOS: u64 = A::GRANULARITY_NANOS;
+
+    fn current_millis(&self) -> T {
+        const {
+            assert!(
+                A::GRANULARITY_NANOS == B::GRANULARITY_NANOS,
+                "MaxOf sources must share the same granularity"
+            );
+        }
+        self.0.current_millis().max(self.1.current_millis())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_saturates_at_both_ends() {
+        let low = OffsetClock::new(|| 5_u64, -10);
+        assert_eq!(low.current_millis(), 0);
+
+        let high = OffsetClock::new(|| u32::MAX - 1, 10);
+        assert_eq!(high.current_millis(), u32::MAX);
+    }
+
+    #[test]
+    fn never_backwards_holds_the_high_water_mark() {
+        use core::cell::Cell;
+
+        let now = Cell::new(50_u128);
+        let clock = NeverBackwards::new(|| now.get());
+        assert_eq!(clock.current_millis(), 50);
+        now.set(20);
+        assert_eq!(clock.current_millis(), 50);
+        now.set(60);
+        assert_eq!(clock.current_millis(), 60);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::time::TimeSource;
+
+/// Any `Fn() -> T` is a [`TimeSource`] with a granularity of one millisecond.
+///
+/// This is the quickest way to plug a hardware tick counter or RTC into a
+/// generator on targets without `std`. Use [`FnClock`] when one returned unit
+/// is not one millisecond.
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     generator::BasicSnowflakeGenerator,
+///     id::SnowflakeTwitterId,
+/// };
+///
+/// fn ticks_ms() -> u64 {
+///     // e.g. read a hardware timer
+///     1_000
+/// }
+///
+/// let generator = BasicSnowflakeGenerator::<SnowflakeTwitterId, _>::new(0, ticks_ms);
+/// let id = generator.next_id(|_| core::hint::spin_loop());
+/// assert_eq!(id.timestamp(), 1_000);
+/// ```
+impl<T, F> TimeSource<T> for F
+where
+    F: Fn() -> T,
+{
+    fn current_millis(&self) -> T {
+        self()
+    }
+}
+
+/// A [`TimeSource`] that wraps a function returning time in `N`-millisecond
+/// units.
+///
+/// Unlike a bare closure, the granularity is part of the type, so generators
+/// and [`TimeSource::units_to_duration`] know how long one returned unit lasts.
+///
+/// # Example
+/// ```
+/// use core::time::Duration;
+///
+/// use ferroid::time::{FnClock, TimeSource};
+///
+/// // A 100 Hz tick counter: one unit is 10 ms.
+/// fn ticks() -> u64 {
+///     42
+/// }
+///
+/// let clock = FnClock::<_, 10>::new(ticks as fn() -> u64);
+/// assert_eq!(clock.current_millis(), 42);
+/// assert_eq!(
+///     <FnClock<fn() -> u64, 10> as TimeSource<u64>>::units_to_duration(3),
+///     Duration::from_millis(30)
+/// );
+/// ```
+///
+/// The granularity must be non-zero:
+///
+/// ```compile_fail
+/// use ferroid::time::FnClock;
+///
+/// let _ = FnClock::<_, 0>::new(|| 0_u64);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FnClock<F, const N: u64 = 1> {
+    read: F,
+}
+
+impl<F, const N: u64> FnClock<F, N> {
+    const ASSERT_VALID_GRANULARITY: () =
+        assert!(N > 0, "FnClock granularity must be greater than zero");
+
+    /// Wraps `read`, which must return the current time in `N`-millisecond
+    /// units since the desired epoch.
+    pub const fn new(read: F) -> Self {
+        let () = Self::ASSERT_VALID_GRANULARITY;
+        Self { read }
+    }
+}
+
+impl<T, F, const N: u64> TimeSource<T> for FnClock<F, N>
+where
+    F: Fn() -> T,
+{
+    const GRANULARITY_MILLIS: u64 = N;
+
+    fn current_millis(&self) -> T {
+        (self.read)()
+    }
+}
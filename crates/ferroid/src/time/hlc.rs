@@ -0,0 +1,294 @@
+use alloc::sync::Arc;
+use core::fmt;
+
+use portable_atomic::{AtomicU64, Ordering};
+
+use crate::{
+    id::ToU64,
+    time::{TimeSource, TimeUnit},
+};
+
+/// A Hybrid Logical Clock (HLC) time source for causally ordered IDs.
+///
+/// Physical clocks on different nodes disagree, so an ID minted on node B
+/// right after receiving a message from node A can sort *before* A's ID when
+/// B's clock runs behind. An HLC fixes this: it reads a physical source `S`,
+/// but never reports less than the latest remote timestamp it has observed
+/// plus one unit.
+///
+/// Call [`observe`] with the timestamp of every ID (or message) received from
+/// another node before minting IDs that must sort after it. Since generators
+/// place the timestamp in the most significant bits, every ID produced after
+/// `observe(ts)` sorts strictly after any ID carrying timestamp `ts`, no matter
+/// which node minted it. When physical time catches up, the clock follows it
+/// again, so the logical lead stays bounded by the skew between nodes.
+///
+/// Generators take the HLC like any other [`TimeSource`], and the timestamp
+/// field carries HLC time. Clones share state, so keep one clone for
+/// `observe` and hand another to the generator.
+///
+/// A remote node with a badly wrong clock can drag every HLC it talks to into
+/// the future. Use [`with_max_drift`] to reject remote timestamps too far ahead
+/// of local physical time.
+///
+/// # ⚠️ Throughput While Ahead
+/// This HLC has no logical counter: while it runs ahead of physical time, it
+/// reports the same timestamp (`remote + 1`) on every read. Generators can
+/// still issue as many IDs as one tick holds (the sequence or random space),
+/// after which they return [`Poll::Pending`] until physical time reaches that
+/// tick. A remote clock running ahead therefore throttles local generation
+/// to one tick's worth of IDs for as long as [`logical_lead`] is non-zero.
+/// With [`with_max_drift`], that stall lasts at most `max_drift + 1` units.
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     generator::BasicSnowflakeGenerator,
+///     id::{SnowflakeId, SnowflakeTwitterId},
+///     testing::ManualClock,
+///     time::HybridLogicalClock,
+/// };
+///
+/// // Node A's clock is 100 ms ahead of node B's.
+/// let gen_a = BasicSnowflakeGenerator::<SnowflakeTwitterId, _>::new(
+///     1,
+///     HybridLogicalClock::new(ManualClock::new(1_100)),
+/// );
+/// let hlc_b = HybridLogicalClock::new(ManualClock::new(1_000));
+/// let gen_b = BasicSnowflakeGenerator::<SnowflakeTwitterId, _>::new(2, hlc_b.clone());
+///
+/// let sent = gen_a.next_id(|_| unreachable!());
+///
+/// // B receives A's message and observes its ID before replying.
+/// hlc_b.observe(sent.timestamp()).unwrap();
+/// let reply = gen_b.next_id(|_| unreachable!());
+///
+/// assert!(reply > sent);
+/// ```
+///
+/// [`observe`]: HybridLogicalClock::observe
+/// [`with_max_drift`]: HybridLogicalClock::with_max_drift
+/// [`logical_lead`]: HybridLogicalClock::logical_lead
+/// [`Poll::Pending`]: crate::generator::Poll::Pending
+#[derive(Clone, Debug)]
+pub struct HybridLogicalClock<S> {
+    physical: S,
+    /// The smallest value this clock may report, in `S`'s units.
+    floor: Arc<AtomicU64>,
+    max_drift: Option<u64>,
+}
+
+#[cfg(all(feature = "std", feature = "alloc", target_has_atomic = "64"))]
+impl Default for HybridLogicalClock<crate::time::MonotonicClock> {
+    /// Constructs an HLC over the default [`MonotonicClock`].
+    ///
+    /// [`MonotonicClock`]: crate::time::MonotonicClock
+    fn default() -> Self {
+        Self::new(crate::time::MonotonicClock::default())
+    }
+}
+
+impl<S> HybridLogicalClock<S> {
+    /// Wraps `physical`, accepting remote timestamps of any size.
+    pub fn new(physical: S) -> Self {
+        Self {
+            physical,
+            floor: Arc::new(AtomicU64::new(0)),
+            max_drift: None,
+        }
+    }
+
+    /// Wraps `physical`, rejecting remote timestamps more than `max_drift`
+    /// units ahead of local physical time.
+    pub fn with_max_drift(physical: S, max_drift: u64) -> Self {
+        Self {
+            max_drift: Some(max_drift),
+            ..Self::new(physical)
+        }
+    }
+
+    /// Returns the wrapped physical time source.
+    pub const fn physical(&self) -> &S {
+        &self.physical
+    }
+
+    /// Merges a timestamp received from another node.
+    ///
+    /// Afterwards, this clock (and every clone of it) reports at least
+    /// `remote + 1` units.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DriftExceeded`] and leaves the clock unchanged if a maximum
+    /// drift is configured and `remote` is further ahead of local physical time
+    /// than allowed.
+    pub fn observe<T>(&self, remote: T) -> Result<(), DriftExceeded>
+    where
+        T: ToU64,
+        S: TimeSource<T>,
+    {
+        let remote = remote.to_u64();
+        if let Some(max_drift) = self.max_drift {
+            let local = self.physical.current_millis().to_u64();
+            if remote > local.saturating_add(max_drift) {
+                return Err(DriftExceeded {
+                    remote,
+                    local,
+                    max_drift,
+                });
+            }
+        }
+        self.floor
+            .fetch_max(remote.saturating_add(1), Ordering::AcqRel);
+        Ok(())
+    }
+
+    /// Returns how far the clock is currently running ahead of its physical
+    /// source because of observed remote timestamps, in `S`'s units.
+    pub fn logical_lead<T>(&self) -> u64
+    where
+        T: ToU64,
+        S: TimeSource<T>,
+    {
+        let physical = self.physical.current_millis().to_u64();
+        self.floor.load(Ordering::Acquire).saturating_sub(physical)
+    }
+}
+
+impl<T, S> TimeSource<T> for HybridLogicalClock<S>
+where
+    T: TimeUnit,
+    S: TimeSource<T>,
+{
+    const GRANULARITY_MILLIS: u64 = S::GRANULARITY_MILLIS;
+    const GRANULARITY_NANOS: u64 = S::GRANULARITY_NANOS;
+
+    fn current_millis(&self) -> T {
+        let physical = self.physical.current_millis();
+        let floor = self.floor.load(Ordering::Acquire);
+        if floor > physical.to_u64() {
+            T::from_u64(floor)
+        } else {
+            physical
+        }
+    }
+}
+
+/// A remote timestamp was too far ahead of local physical time.
+///
+/// Returned by [`HybridLogicalClock::observe`] when a maximum drift is
+/// configured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DriftExceeded {
+    /// The rejected remote timestamp.
+    pub remote: u64,
+    /// The local physical time when it was observed.
+    pub local: u64,
+    /// The configured maximum drift.
+    pub max_drift: u64,
+}
+
+impl fmt::Display for DriftExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "remote timestamp {} is more than {} units ahead of local time {}",
+            self.remote, self.max_drift, self.local
+        )
+    }
+}
+
+impl core::error::Error for DriftExceeded {}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualClock;
+
+    #[test]
+    fn follows_physical_time_once_it_catches_up() {
+        let physical = ManualClock::new(10);
+        let hlc = HybridLogicalClock::new(physical.clone());
+
+        hlc.observe(20_u64).unwrap();
+        assert_eq!(TimeSource::<u64>::current_millis(&hlc), 21);
+        assert_eq!(hlc.logical_lead::<u64>(), 11);
+
+        // Older remote timestamps never move the clock back.
+        hlc.observe(5_u64).unwrap();
+        assert_eq!(TimeSource::<u64>::current_millis(&hlc), 21);
+
+        physical.set(30);
+        assert_eq!(TimeSource::<u64>::current_millis(&hlc), 30);
+        assert_eq!(hlc.logical_lead::<u64>(), 0);
+    }
+
+    #[test]
+    fn rejects_remote_timestamps_beyond_max_drift() {
+        let hlc = HybridLogicalClock::with_max_drift(ManualClock::new(100), 50);
+
+        hlc.observe(150_u64).unwrap();
+        assert_eq!(
+            hlc.observe(151_u64),
+            Err(DriftExceeded {
+                remote: 151,
+                local: 100,
+                max_drift: 50,
+            })
+        );
+        assert_eq!(TimeSource::<u64>::current_millis(&hlc), 151);
+    }
+
+    #[cfg(all(feature = "snowflake", feature = "basic"))]
+    #[test]
+    fn exhausted_tick_stalls_until_physical_time_catches_up() {
+        use crate::{
+            generator::{BasicSnowflakeGenerator, Poll},
+            id::SnowflakeTwitterId,
+        };
+
+        let physical = ManualClock::new(10);
+        let hlc = HybridLogicalClock::with_max_drift(physical.clone(), 10);
+        hlc.observe(20_u64).unwrap();
+
+        // The pinned tick's sequence is already used up.
+        let generator = BasicSnowflakeGenerator::<SnowflakeTwitterId, _>::from_components(
+            21,
+            0,
+            SnowflakeTwitterId::max_sequence(),
+            hlc.clone(),
+        );
+        assert!(matches!(generator.poll_id(), Poll::Pending { .. }));
+
+        physical.set(20);
+        assert!(matches!(generator.poll_id(), Poll::Pending { .. }));
+
+        // Within `max_drift + 1` units, physical time passes the pinned tick.
+        physical.set(22);
+        assert_eq!(hlc.logical_lead::<u64>(), 0);
+        assert!(matches!(
+            generator.poll_id(),
+            Poll::Ready { id } if id.timestamp() == 22
+        ));
+    }
+
+    #[cfg(all(feature = "ulid", feature = "basic"))]
+    #[test]
+    fn ulids_minted_after_observe_sort_after_the_remote_id() {
+        use crate::{generator::BasicMonoUlidGenerator, id::ULID, testing::SeededRandom};
+
+        let remote = BasicMonoUlidGenerator::<ULID, _, _>::new(
+            ManualClock::new(5_000),
+            SeededRandom::new(1),
+        )
+        .next_id(|_| unreachable!());
+
+        let hlc = HybridLogicalClock::new(ManualClock::new(4_000));
+        let local = BasicMonoUlidGenerator::<ULID, _, _>::new(hlc.clone(), SeededRandom::new(2));
+
+        hlc.observe(remote.timestamp()).unwrap();
+        let reply = local.next_id(|_| unreachable!());
+        assert!(reply > remote);
+        assert_eq!(reply.timestamp(), remote.timestamp() + 1);
+    }
+}
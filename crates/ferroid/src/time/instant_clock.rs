@@ -0,0 +1,122 @@
+use core::time::Duration;
+use std::time::{Instant, SystemTime};
+
+use crate::time::{TimeSource, UNIX_EPOCH};
+
+/// A monotonic time source that reads [`Instant`] on every call.
+///
+/// Like [`MonotonicClock`], the wall clock is sampled once at construction to
+/// anchor the epoch, after which only the monotonic [`Instant`] is consulted,
+/// so readings never go backwards. Unlike [`MonotonicClock`], no background
+/// thread is spawned, which makes it suitable for sandboxes that forbid
+/// threads, short-lived processes, and `fork`-heavy programs. Each read costs
+/// a (vDSO) clock read rather than an atomic load.
+///
+/// `N` controls the number of real milliseconds represented by one returned
+/// time unit. For sub-millisecond units, see [`MonotonicMicrosClock`].
+///
+/// # Example
+/// ```
+/// use ferroid::time::{InstantClock, TWITTER_EPOCH, TimeSource};
+///
+/// let clock = InstantClock::<1>::with_epoch(TWITTER_EPOCH);
+/// let a: u64 = clock.current_millis();
+/// let b: u64 = clock.current_millis();
+/// assert!(b >= a);
+/// ```
+///
+/// The granularity must be non-zero:
+///
+/// ```compile_fail
+/// use ferroid::time::{InstantClock, UNIX_EPOCH};
+///
+/// let _ = InstantClock::<0>::with_epoch(UNIX_EPOCH);
+/// ```
+///
+/// [`MonotonicClock`]: crate::time::MonotonicClock
+/// [`MonotonicMicrosClock`]: crate::time::MonotonicMicrosClock
+#[derive(Clone, Copy, Debug)]
+pub struct InstantClock<const N: u64 = 1> {
+    start: Instant,
+    epoch_offset: u64, // in milliseconds
+}
+
+impl Default for InstantClock<1> {
+    /// Constructs an instant clock aligned to the default [`UNIX_EPOCH`].
+    fn default() -> Self {
+        Self::with_epoch(UNIX_EPOCH)
+    }
+}
+
+impl<const N: u64> InstantClock<N> {
+    const ASSERT_VALID_GRANULARITY: () =
+        assert!(N > 0, "InstantClock granularity must be greater than zero");
+    pub const GRANULARITY_MILLIS: u64 = N;
+
+    /// Constructs an instant clock using `epoch` (a [`Duration`] since
+    /// 1970-01-01 UTC) as the origin.
+    #[must_use]
+    pub fn with_epoch(epoch: Duration) -> Self {
+        let () = Self::ASSERT_VALID_GRANULARITY;
+        let start = Instant::now();
+        let system_now = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let epoch_offset = system_now.saturating_sub(epoch).as_millis() as u64;
+
+        Self {
+            start,
+            epoch_offset,
+        }
+    }
+}
+
+impl<const N: u64> TimeSource<u64> for InstantClock<N> {
+    const GRANULARITY_MILLIS: u64 = Self::GRANULARITY_MILLIS;
+
+    /// Returns the number of `N`-millisecond units since the configured epoch.
+    fn current_millis(&self) -> u64 {
+        let () = Self::ASSERT_VALID_GRANULARITY;
+        #[allow(clippy::cast_possible_truncation)]
+        let elapsed = self.start.elapsed().as_millis() as u64;
+        (self.epoch_offset + elapsed) / N
+    }
+}
+
+impl<const N: u64> TimeSource<u128> for InstantClock<N> {
+    const GRANULARITY_MILLIS: u64 = Self::GRANULARITY_MILLIS;
+
+    /// Returns the number of `N`-millisecond units since the configured epoch.
+    fn current_millis(&self) -> u128 {
+        u128::from(<Self as TimeSource<u64>>::current_millis(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advances_with_elapsed_time() {
+        let clock = InstantClock::default();
+        let before = <InstantClock as TimeSource<u64>>::current_millis(&clock);
+        std::thread::sleep(Duration::from_millis(5));
+        let after = <InstantClock as TimeSource<u64>>::current_millis(&clock);
+        assert!(after >= before + 5);
+    }
+
+    #[test]
+    fn quantizes_into_n_millisecond_units() {
+        let millis = InstantClock::<1>::with_epoch(UNIX_EPOCH);
+        let coarse = InstantClock::<8>::with_epoch(UNIX_EPOCH);
+
+        let lower = <InstantClock<1> as TimeSource<u64>>::current_millis(&millis);
+        let quantized = <InstantClock<8> as TimeSource<u64>>::current_millis(&coarse);
+        let upper = <InstantClock<1> as TimeSource<u64>>::current_millis(&millis);
+
+        assert!(lower / 8 <= quantized + 1);
+        assert!(quantized <= upper / 8 + 1);
+    }
+}
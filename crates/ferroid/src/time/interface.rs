@@ -1,5 +1,7 @@
 use core::time::Duration;
 
+use crate::id::ToU64;
+
 /// Unix epoch: Thursday, January 1, 1970 00:00:00 UTC
 pub const UNIX_EPOCH: Duration = Duration::from_millis(0);
 
@@ -24,9 +26,13 @@ pub const MASTODON_EPOCH: Duration = UNIX_EPOCH;
 ///
 /// By default, one returned time unit corresponds to one real millisecond.
 /// Time sources may override [`GRANULARITY_MILLIS`] to expose coarser clock
-/// quanta while still allowing generic code to recover the real duration of a
+/// quanta, or [`GRANULARITY_NANOS`] to expose finer ones (such as microsecond
+/// ticks), while still allowing generic code to recover the real duration of a
 /// single returned unit.
 ///
+/// [`GRANULARITY_MILLIS`]: TimeSource::GRANULARITY_MILLIS
+/// [`GRANULARITY_NANOS`]: TimeSource::GRANULARITY_NANOS
+///
 /// # Example
 /// ```
 /// use ferroid::time::TimeSource;
@@ -43,9 +49,42 @@ pub const MASTODON_EPOCH: Duration = UNIX_EPOCH;
 /// ```
 pub trait TimeSource<T> {
     /// The number of real milliseconds represented by one returned time unit.
+    ///
+    /// Sub-millisecond sources round this down, so it is `0` for them; use
+    /// [`GRANULARITY_NANOS`] when the exact duration matters.
+    ///
+    /// [`GRANULARITY_NANOS`]: TimeSource::GRANULARITY_NANOS
     const GRANULARITY_MILLIS: u64 = 1;
 
+    /// The number of real nanoseconds represented by one returned time unit.
+    ///
+    /// Defaults to [`GRANULARITY_MILLIS`] converted to nanoseconds. Sources
+    /// with sub-millisecond units override this (and set
+    /// [`GRANULARITY_MILLIS`] to `0`).
+    ///
+    /// [`GRANULARITY_MILLIS`]: TimeSource::GRANULARITY_MILLIS
+    const GRANULARITY_NANOS: u64 = Self::GRANULARITY_MILLIS.saturating_mul(1_000_000);
+
     /// Returns the current time since the configured epoch in this source's
     /// native units.
     fn current_millis(&self) -> T;
+
+    /// Converts a number of this source's native units (such as a generator's
+    /// `yield_for`) into a real [`Duration`].
+    ///
+    /// # Example
+    /// ```
+    /// use core::time::Duration;
+    ///
+    /// use ferroid::time::{MonotonicClock, TimeSource};
+    ///
+    /// let dur = <MonotonicClock<8> as TimeSource<u64>>::units_to_duration(2);
+    /// assert_eq!(dur, Duration::from_millis(16));
+    /// ```
+    fn units_to_duration(units: T) -> Duration
+    where
+        T: ToU64,
+    {
+        Duration::from_nanos(units.to_u64().saturating_mul(Self::GRANULARITY_NANOS))
+    }
 }
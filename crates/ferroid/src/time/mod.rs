@@ -1,7 +1,33 @@
+#[cfg(all(feature = "std", target_os = "linux"))]
+mod coarse_clock;
+mod combinators;
+mod fn_clock;
+#[cfg(all(feature = "alloc", target_has_atomic = "64"))]
+mod hlc;
+#[cfg(feature = "std")]
+mod instant_clock;
 mod interface;
 #[cfg(all(feature = "std", feature = "alloc", target_has_atomic = "64"))]
 mod mono_clock;
+#[cfg(feature = "std")]
+mod mono_micros_clock;
+mod rtc_clock;
+#[cfg(feature = "std")]
+mod system_clock;
 
+#[cfg(all(feature = "std", target_os = "linux"))]
+pub use coarse_clock::*;
+pub use combinators::*;
+pub use fn_clock::*;
+#[cfg(all(feature = "alloc", target_has_atomic = "64"))]
+pub use hlc::*;
+#[cfg(feature = "std")]
+pub use instant_clock::*;
 pub use interface::*;
 #[cfg(all(feature = "std", feature = "alloc", target_has_atomic = "64"))]
 pub use mono_clock::*;
+#[cfg(feature = "std")]
+pub use mono_micros_clock::*;
+pub use rtc_clock::*;
+#[cfg(feature = "std")]
+pub use system_clock::*;
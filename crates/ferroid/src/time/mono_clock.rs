@@ -1,40 +1,75 @@
-use alloc::sync::Arc;
+use alloc::sync::{Arc, Weak};
 use core::time::Duration;
 use std::{
-    sync::{LazyLock, OnceLock},
-    thread::{self, JoinHandle},
+    sync::{LazyLock, Mutex, MutexGuard, OnceLock, PoisonError},
+    thread::{self, JoinHandle, Thread},
     time::{Instant, SystemTime},
 };
 
-use portable_atomic::{AtomicU64, Ordering};
+use portable_atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
 
 use crate::time::{TimeSource, UNIX_EPOCH};
 
+/// How long the ticker keeps running without any reads before parking.
+const IDLE_TIMEOUT_MS: u64 = 1_000;
+
 /// Lazily initialized, process-global ticker.
 ///
-/// The background thread is started on first use of [`MonotonicClock`], and
-/// it runs for the lifetime of the process.
-static GLOBAL_TICKER: LazyLock<Arc<SharedTickerInner>> = LazyLock::new(|| {
-    let start = Instant::now();
-    let system_now = SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or(Duration::ZERO);
-    #[allow(clippy::cast_possible_truncation)]
-    let base_system_now = system_now.as_millis() as u64;
-
-    let inner = Arc::new(SharedTickerInner {
-        current: AtomicU64::new(0),
-        handle: OnceLock::new(),
-        base_system_now,
-    });
-
-    let inner_ref = Arc::clone(&inner);
-    let handle = thread::spawn(move || {
+/// The background thread is started on first use of [`MonotonicClock`]. It
+/// parks itself after [`IDLE_TIMEOUT_MS`] without reads and runs until
+/// [`MonotonicClock::shutdown_ticker`] is called.
+static GLOBAL_TICKER: LazyLock<Arc<SharedTickerInner>> =
+    LazyLock::new(|| SharedTickerInner::spawn(IDLE_TIMEOUT_MS));
+
+/// Shared ticker thread that updates every millisecond while in use.
+#[derive(Debug)]
+struct SharedTickerInner {
+    current: AtomicU64,
+    /// Set by readers, cleared by the ticker once per idle window. While
+    /// clear, readers take the slow path: they resync `current` from
+    /// [`Instant`] and wake the ticker.
+    active: AtomicBool,
+    shutdown: AtomicBool,
+    thread: OnceLock<Thread>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+    start: Instant,
+    base_system_now: u64,
+}
+
+impl SharedTickerInner {
+    fn spawn(idle_timeout_ms: u64) -> Arc<Self> {
+        let start = Instant::now();
+        let system_now = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+        #[allow(clippy::cast_possible_truncation)]
+        let base_system_now = system_now.as_millis() as u64;
+
+        let inner = Arc::new(Self {
+            current: AtomicU64::new(0),
+            active: AtomicBool::new(true),
+            shutdown: AtomicBool::new(false),
+            thread: OnceLock::new(),
+            handle: Mutex::new(None),
+            start,
+            base_system_now,
+        });
+
+        let inner_ref = Arc::clone(&inner);
+        let handle = thread::spawn(move || inner_ref.run(idle_timeout_ms));
+
+        let _ = inner.thread.set(handle.thread().clone());
+        *inner.lock_handle() = Some(handle);
+        inner
+    }
+
+    fn run(&self, idle_timeout_ms: u64) {
         let mut tick = 0;
+        let mut idle_deadline = idle_timeout_ms;
 
-        loop {
+        while !self.shutdown.load(Ordering::Acquire) {
             // Compute the absolute target time of the next tick
-            let target = start + Duration::from_millis(tick);
+            let target = self.start + Duration::from_millis(tick);
 
             // Sleep if we are early
             let now = Instant::now();
@@ -43,28 +78,212 @@ static GLOBAL_TICKER: LazyLock<Arc<SharedTickerInner>> = LazyLock::new(|| {
             }
 
             // After waking, recompute how far we actually are from the
-            // start
-            #[allow(clippy::cast_possible_truncation)]
-            let now_ms = start.elapsed().as_millis() as u64;
+            // start. Readers may have resynced `current` in the meantime, so
+            // only ever move it forward.
+            let mut now_ms = self.resync();
 
-            // Monotonic store, aligned to elapsed milliseconds since start
-            inner_ref.current.store(now_ms, Ordering::Relaxed);
+            if now_ms >= idle_deadline {
+                if !self.active.swap(false, Ordering::SeqCst) {
+                    // Nobody read the clock for a whole window: sleep until a
+                    // reader (or shutdown) wakes us.
+                    while !self.active.load(Ordering::SeqCst)
+                        && !self.shutdown.load(Ordering::SeqCst)
+                    {
+                        thread::park();
+                    }
+                    now_ms = self.resync();
+                }
+                idle_deadline = now_ms + idle_timeout_ms;
+            }
 
             // Align to next tick after the current actual time
             tick = now_ms + 1;
         }
-    });
 
-    let _ = inner.handle.set(handle);
-    inner
-});
+        // Send every future read down the slow path.
+        self.active.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns the elapsed milliseconds since the ticker started.
+    #[inline]
+    fn now_millis(&self) -> u64 {
+        if self.active.load(Ordering::Relaxed) {
+            self.current.load(Ordering::Relaxed)
+        } else {
+            self.wake()
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn wake(&self) -> u64 {
+        if !self.shutdown.load(Ordering::SeqCst) {
+            self.active.store(true, Ordering::SeqCst);
+            if let Some(thread) = self.thread.get() {
+                thread.unpark();
+            }
+            // Lost a race with shutdown: keep later reads on the slow path.
+            if self.shutdown.load(Ordering::SeqCst) {
+                self.active.store(false, Ordering::SeqCst);
+            }
+        }
+        self.resync()
+    }
+
+    /// Advances `current` to the time elapsed according to [`Instant`] and
+    /// returns the new value.
+    fn resync(&self) -> u64 {
+        #[allow(clippy::cast_possible_truncation)]
+        let now_ms = self.start.elapsed().as_millis() as u64;
+        let prev = self.current.fetch_max(now_ms, Ordering::Relaxed);
+        prev.max(now_ms)
+    }
+
+    fn shutdown(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        let handle = self.lock_handle().take();
+        if let Some(handle) = handle {
+            handle.thread().unpark();
+            // The ticker never panics; if it somehow did, there is nothing
+            // left to clean up.
+            let _ = handle.join();
+        }
+    }
+
+    fn lock_handle(&self) -> MutexGuard<'_, Option<JoinHandle<()>>> {
+        self.handle.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns how far the wall clock is ahead of the ticker's view of it, in
+    /// microseconds (negative if the wall clock is behind).
+    fn wall_clock_drift_micros(&self) -> i64 {
+        let elapsed = self.start.elapsed();
+        let wall = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+        #[allow(clippy::cast_possible_truncation)]
+        let wall_micros = wall.as_micros() as i128;
+        let ticker_micros = i128::from(self.base_system_now) * 1_000 + elapsed.as_micros() as i128;
+        #[allow(clippy::cast_possible_truncation)]
+        let drift =
+            (wall_micros - ticker_micros).clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64;
+        drift
+    }
+}
+
+/// Configuration for [`MonotonicClock::with_drift_correction`].
+///
+/// Every `interval`, the clock compares its reading against
+/// [`SystemTime::now`] and moves its correction toward the difference by at
+/// most `max_slew_ppm` parts per million of the elapsed interval. A slew rate
+/// of 500 ppm (the default, matching NTP) corrects 1 second of drift in about
+/// 33 minutes.
+///
+/// Slewing never makes the clock go backwards: when the wall clock is behind,
+/// the clock only runs slower until the two agree. Large wall-clock steps
+/// (e.g. a manual `date` change) are therefore absorbed gradually, not jumped
+/// to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DriftCorrection {
+    interval: Duration,
+    max_slew_ppm: u32,
+}
+
+impl DriftCorrection {
+    /// Creates a configuration sampling every `interval` and slewing at most
+    /// `max_slew_ppm` parts per million.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    #[must_use]
+    pub const fn new(interval: Duration, max_slew_ppm: u32) -> Self {
+        assert!(
+            !interval.is_zero(),
+            "drift correction interval must be non-zero"
+        );
+        Self {
+            interval,
+            max_slew_ppm,
+        }
+    }
+
+    /// How often the wall clock is sampled.
+    #[must_use]
+    pub const fn interval(&self) -> Duration {
+        self.interval
+    }
 
-/// Shared ticker thread that updates every millisecond.
+    /// The maximum correction rate, in parts per million.
+    #[must_use]
+    pub const fn max_slew_ppm(&self) -> u32 {
+        self.max_slew_ppm
+    }
+
+    /// The largest correction, in microseconds, applied after one interval.
+    fn max_step_micros(&self) -> i64 {
+        let step = self.interval.as_micros() * u128::from(self.max_slew_ppm) / 1_000_000;
+        i64::try_from(step).unwrap_or(i64::MAX)
+    }
+}
+
+impl Default for DriftCorrection {
+    /// Samples once per second and slews at most 500 ppm.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), 500)
+    }
+}
+
+/// Per-clock drift correction state, updated by a background thread.
 #[derive(Debug)]
-struct SharedTickerInner {
-    current: AtomicU64,
-    handle: OnceLock<JoinHandle<()>>,
-    base_system_now: u64,
+struct DriftState {
+    /// The correction currently applied to ticker readings, in microseconds.
+    offset_micros: AtomicI64,
+    /// The largest value returned so far, in milliseconds since the epoch.
+    high_water: AtomicU64,
+}
+
+impl DriftState {
+    fn spawn(ticker: Arc<SharedTickerInner>, config: DriftCorrection) -> Arc<Self> {
+        let state = Arc::new(Self {
+            offset_micros: AtomicI64::new(0),
+            high_water: AtomicU64::new(0),
+        });
+        let weak: Weak<Self> = Arc::downgrade(&state);
+        let max_step = config.max_step_micros();
+
+        // The thread exits once every clone of the owning clock is dropped.
+        thread::spawn(move || {
+            loop {
+                thread::sleep(config.interval);
+                let Some(state) = weak.upgrade() else {
+                    break;
+                };
+                let applied = state.offset_micros.load(Ordering::Relaxed);
+                let target = ticker.wall_clock_drift_micros();
+                state
+                    .offset_micros
+                    .store(slew(applied, target, max_step), Ordering::Relaxed);
+            }
+        });
+
+        state
+    }
+
+    /// Applies the current correction to `millis`, never returning less than a
+    /// previous call.
+    fn correct(&self, millis: u64) -> u64 {
+        let offset = self.offset_micros.load(Ordering::Relaxed).div_euclid(1_000);
+        let corrected = millis.saturating_add_signed(offset);
+        let prev = self.high_water.fetch_max(corrected, Ordering::Relaxed);
+        prev.max(corrected)
+    }
+}
+
+/// Moves `applied` toward `target` by at most `max_step`.
+fn slew(applied: i64, target: i64, max_step: i64) -> i64 {
+    let step = target.saturating_sub(applied).clamp(-max_step, max_step);
+    applied.saturating_add(step)
 }
 
 /// A monotonic time source that returns elapsed time since process start,
@@ -86,10 +305,18 @@ struct SharedTickerInner {
 ///
 /// let _ = MonotonicClock::<0>::with_epoch(UNIX_EPOCH);
 /// ```
+///
+/// Because only the monotonic timer is consulted after startup, readings
+/// slowly drift away from wall-clock time over long uptimes. Use
+/// [`with_drift_correction`] to slew back toward [`SystemTime`] without ever
+/// going backwards.
+///
+/// [`with_drift_correction`]: MonotonicClock::with_drift_correction
 #[derive(Clone, Debug)]
 pub struct MonotonicClock<const N: u64 = 1> {
     inner: Arc<SharedTickerInner>,
     epoch_offset: u64, // in milliseconds
+    drift: Option<Arc<DriftState>>,
 }
 
 impl Default for MonotonicClock<1> {
@@ -99,6 +326,36 @@ impl Default for MonotonicClock<1> {
     }
 }
 
+impl MonotonicClock {
+    /// Stops the shared background ticker and waits for its thread to exit.
+    ///
+    /// The ticker already parks itself after about a second without reads, so
+    /// this is only needed when the thread must be gone, e.g. before a
+    /// `fork`, at the end of a serverless invocation, or to satisfy a
+    /// leak-checking test harness.
+    ///
+    /// Existing and future clocks keep working after shutdown: every read
+    /// falls back to querying [`Instant`] directly, which is slower but
+    /// equally monotonic. The ticker cannot be restarted. Calling this more
+    /// than once is harmless.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::time::{MonotonicClock, TimeSource};
+    ///
+    /// let clock = MonotonicClock::default();
+    /// let before: u64 = clock.current_millis();
+    ///
+    /// MonotonicClock::shutdown_ticker();
+    ///
+    /// let after: u64 = clock.current_millis();
+    /// assert!(after >= before);
+    /// ```
+    pub fn shutdown_ticker() {
+        GLOBAL_TICKER.shutdown();
+    }
+}
+
 impl<const N: u64> MonotonicClock<N> {
     const ASSERT_VALID_GRANULARITY: () = assert!(
         N > 0,
@@ -170,8 +427,55 @@ impl<const N: u64> MonotonicClock<N> {
         Self {
             inner,
             epoch_offset: offset,
+            drift: None,
         }
     }
+
+    /// Constructs a monotonic clock like [`with_epoch`] that also corrects for
+    /// drift against the wall clock.
+    ///
+    /// A background thread samples [`SystemTime::now`] every
+    /// [`DriftCorrection::interval`] and slews this clock toward it at no more
+    /// than [`DriftCorrection::max_slew_ppm`] parts per million. The clock
+    /// never goes backwards: when the wall clock is behind, readings advance
+    /// more slowly (and may repeat) until the two agree. The thread exits once
+    /// every clone of the clock is dropped.
+    ///
+    /// Each read costs an extra atomic read-modify-write compared to an
+    /// uncorrected clock.
+    ///
+    /// # Example
+    /// ```
+    /// use ferroid::time::{DriftCorrection, MonotonicClock, TimeSource, UNIX_EPOCH};
+    ///
+    /// let clock = MonotonicClock::<1>::with_drift_correction(UNIX_EPOCH, DriftCorrection::default());
+    /// let _ts: u64 = clock.current_millis();
+    ///
+    /// // Report the correction currently applied, e.g. to a metrics gauge.
+    /// let _offset = clock.drift_offset_micros();
+    /// ```
+    ///
+    /// [`with_epoch`]: MonotonicClock::with_epoch
+    #[must_use]
+    pub fn with_drift_correction(epoch: Duration, correction: DriftCorrection) -> Self {
+        let mut clock = Self::with_epoch(epoch);
+        clock.drift = Some(DriftState::spawn(Arc::clone(&clock.inner), correction));
+        clock
+    }
+
+    /// Returns the drift correction currently applied, in microseconds.
+    ///
+    /// Positive values mean the clock is being moved ahead of the raw
+    /// monotonic reading (the wall clock was ahead). Always `0` for clocks
+    /// created without [`with_drift_correction`].
+    ///
+    /// [`with_drift_correction`]: MonotonicClock::with_drift_correction
+    #[must_use]
+    pub fn drift_offset_micros(&self) -> i64 {
+        self.drift
+            .as_ref()
+            .map_or(0, |drift| drift.offset_micros.load(Ordering::Relaxed))
+    }
 }
 
 impl<const N: u64> TimeSource<u64> for MonotonicClock<N> {
@@ -181,7 +485,11 @@ impl<const N: u64> TimeSource<u64> for MonotonicClock<N> {
     /// based on the elapsed monotonic time since construction.
     fn current_millis(&self) -> u64 {
         let () = Self::ASSERT_VALID_GRANULARITY;
-        (self.epoch_offset + self.inner.current.load(Ordering::Relaxed)) / N
+        let millis = self.epoch_offset + self.inner.now_millis();
+        match &self.drift {
+            None => millis / N,
+            Some(drift) => drift.correct(millis) / N,
+        }
     }
 }
 
@@ -224,4 +532,89 @@ mod tests {
         assert!(lower / 8 <= quantized);
         assert!(quantized <= upper / 8);
     }
+
+    #[test]
+    fn slew_is_capped_in_both_directions() {
+        assert_eq!(slew(0, 10_000, 500), 500);
+        assert_eq!(slew(0, -10_000, 500), -500);
+        assert_eq!(slew(100, 300, 500), 300);
+        assert_eq!(slew(i64::MAX, i64::MIN, i64::MAX), 0);
+    }
+
+    #[test]
+    fn max_step_scales_with_interval() {
+        let config = DriftCorrection::new(Duration::from_secs(2), 500);
+        assert_eq!(config.max_step_micros(), 1_000);
+        assert_eq!(DriftCorrection::default().max_step_micros(), 500);
+    }
+
+    #[test]
+    fn drift_correction_never_goes_backwards() {
+        let state = DriftState {
+            offset_micros: AtomicI64::new(5_000),
+            high_water: AtomicU64::new(0),
+        };
+        assert_eq!(state.correct(100), 105);
+
+        // A negative correction holds the clock instead of rewinding it.
+        state.offset_micros.store(-2_000, Ordering::Relaxed);
+        assert_eq!(state.correct(101), 105);
+        assert_eq!(state.correct(108), 106);
+    }
+
+    #[test]
+    fn uncorrected_clock_reports_zero_offset() {
+        let clock = MonotonicClock::default();
+        assert_eq!(clock.drift_offset_micros(), 0);
+
+        let corrected =
+            MonotonicClock::<1>::with_drift_correction(UNIX_EPOCH, DriftCorrection::default());
+        let plain = <MonotonicClock as TimeSource<u64>>::current_millis(&clock);
+        let with_drift = <MonotonicClock as TimeSource<u64>>::current_millis(&corrected);
+        assert!(with_drift.abs_diff(plain) < 1_000);
+    }
+
+    #[test]
+    fn ticker_resyncs_after_parking() {
+        // Generous bound so loaded runners only slow the test down.
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let ticker = SharedTickerInner::spawn(10);
+
+        // Wait for the ticker to go idle without reading through it.
+        while ticker.active.load(Ordering::SeqCst) {
+            assert!(Instant::now() < deadline, "ticker never went idle");
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        // `current` may be stale while idle; a read must resync it.
+        #[allow(clippy::cast_possible_truncation)]
+        let elapsed = ticker.start.elapsed().as_millis() as u64;
+        let after = ticker.now_millis();
+        assert!(
+            after >= elapsed,
+            "stale read after parking: {after} < {elapsed}"
+        );
+
+        // The read woke the ticker, which advances `current` on its own again.
+        while ticker.current.load(Ordering::SeqCst) <= after {
+            assert!(Instant::now() < deadline, "ticker did not resume");
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        ticker.shutdown();
+    }
+
+    #[test]
+    fn ticker_shutdown_joins_and_reads_keep_advancing() {
+        let ticker = SharedTickerInner::spawn(IDLE_TIMEOUT_MS);
+        ticker.shutdown();
+        assert!(ticker.lock_handle().is_none());
+
+        let before = ticker.now_millis();
+        thread::sleep(Duration::from_millis(5));
+        assert!(ticker.now_millis() >= before + 5);
+
+        // A second shutdown is a no-op.
+        ticker.shutdown();
+    }
 }
@@ -0,0 +1,145 @@
+use core::time::Duration;
+use std::time::{Instant, SystemTime};
+
+use crate::time::{TimeSource, UNIX_EPOCH};
+
+/// A monotonic time source with microsecond resolution.
+///
+/// [`MonotonicClock`] is fed by a background thread that ticks once per
+/// millisecond, which is too coarse for layouts with a microsecond timestamp
+/// field. This clock instead reads [`Instant`] directly on every call, so each
+/// read costs a (vDSO) clock read rather than an atomic load.
+///
+/// Like [`MonotonicClock`], the wall-clock time is sampled once at
+/// construction to anchor the epoch, after which only the monotonic
+/// [`Instant`] is consulted; adjustments to the system clock never move it
+/// backwards.
+///
+/// Returned values are `N`-microsecond units since the configured epoch.
+///
+/// # Example
+/// ```
+/// use core::time::Duration;
+///
+/// use ferroid::time::{MonotonicMicrosClock, TimeSource, UNIX_EPOCH};
+///
+/// let clock = MonotonicMicrosClock::<1>::with_epoch(UNIX_EPOCH);
+/// let a: u64 = clock.current_millis();
+/// let b: u64 = clock.current_millis();
+/// assert!(b >= a);
+///
+/// assert_eq!(
+///     <MonotonicMicrosClock as TimeSource<u64>>::units_to_duration(250),
+///     Duration::from_micros(250)
+/// );
+/// ```
+///
+/// The granularity must be non-zero:
+///
+/// ```compile_fail
+/// use ferroid::time::{MonotonicMicrosClock, UNIX_EPOCH};
+///
+/// let _ = MonotonicMicrosClock::<0>::with_epoch(UNIX_EPOCH);
+/// ```
+///
+/// [`MonotonicClock`]: crate::time::MonotonicClock
+#[derive(Clone, Copy, Debug)]
+pub struct MonotonicMicrosClock<const N: u64 = 1> {
+    start: Instant,
+    epoch_offset: u64, // in microseconds
+}
+
+impl Default for MonotonicMicrosClock<1> {
+    /// Constructs a microsecond clock aligned to the default [`UNIX_EPOCH`].
+    fn default() -> Self {
+        Self::with_epoch(UNIX_EPOCH)
+    }
+}
+
+impl<const N: u64> MonotonicMicrosClock<N> {
+    const ASSERT_VALID_GRANULARITY: () = assert!(
+        N > 0,
+        "MonotonicMicrosClock granularity must be greater than zero"
+    );
+    pub const GRANULARITY_MICROS: u64 = N;
+
+    /// Constructs a microsecond clock using a custom epoch as the origin
+    /// (t = 0), specified as a [`Duration`] since 1970-01-01 UTC.
+    #[must_use]
+    pub fn with_epoch(epoch: Duration) -> Self {
+        let () = Self::ASSERT_VALID_GRANULARITY;
+        let start = Instant::now();
+        let system_now = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let epoch_offset = system_now.saturating_sub(epoch).as_micros() as u64;
+
+        Self {
+            start,
+            epoch_offset,
+        }
+    }
+}
+
+impl<const N: u64> TimeSource<u64> for MonotonicMicrosClock<N> {
+    const GRANULARITY_MILLIS: u64 = N / 1_000;
+    const GRANULARITY_NANOS: u64 = N * 1_000;
+
+    /// Returns the number of `N`-microsecond units since the configured epoch.
+    fn current_millis(&self) -> u64 {
+        let () = Self::ASSERT_VALID_GRANULARITY;
+        #[allow(clippy::cast_possible_truncation)]
+        let elapsed = self.start.elapsed().as_micros() as u64;
+        (self.epoch_offset + elapsed) / N
+    }
+}
+
+impl<const N: u64> TimeSource<u128> for MonotonicMicrosClock<N> {
+    const GRANULARITY_MILLIS: u64 = N / 1_000;
+    const GRANULARITY_NANOS: u64 = N * 1_000;
+
+    /// Returns the number of `N`-microsecond units since the configured epoch.
+    fn current_millis(&self) -> u128 {
+        u128::from(<Self as TimeSource<u64>>::current_millis(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_microseconds_since_epoch() {
+        let clock = MonotonicMicrosClock::default();
+        let before = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap();
+        let now = <MonotonicMicrosClock as TimeSource<u64>>::current_millis(&clock);
+        let after = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap();
+
+        // Allow for the time between sampling the anchor and `before`.
+        let slack = Duration::from_millis(50).as_micros();
+        assert!(u128::from(now) + slack >= before.as_micros());
+        assert!(u128::from(now) <= after.as_micros() + slack);
+    }
+
+    #[test]
+    fn granularity_is_expressed_in_nanoseconds() {
+        assert_eq!(
+            <MonotonicMicrosClock as TimeSource<u64>>::GRANULARITY_NANOS,
+            1_000
+        );
+        assert_eq!(
+            <MonotonicMicrosClock as TimeSource<u64>>::GRANULARITY_MILLIS,
+            0
+        );
+        assert_eq!(
+            <MonotonicMicrosClock<2_000> as TimeSource<u64>>::GRANULARITY_MILLIS,
+            2
+        );
+    }
+}
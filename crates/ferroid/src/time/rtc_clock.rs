@@ -0,0 +1,121 @@
+use core::time::Duration;
+
+use crate::time::{TimeSource, UNIX_EPOCH};
+
+/// A [`TimeSource`] adapter for real-time clocks that report whole seconds
+/// plus a sub-second tick counter.
+///
+/// `read` returns `(seconds, subticks)`, where `seconds` counts from the Unix
+/// epoch and `subticks` counts `TICKS_PER_SECOND` ticks within the current
+/// second (for example, a 32.768 kHz RTC prescaler). The reading is converted
+/// to milliseconds, shifted to the configured epoch, and quantized into
+/// `N`-millisecond units.
+///
+/// Readings before the epoch clamp to zero, and out-of-range sub-ticks are
+/// carried into the seconds.
+///
+/// # Example
+/// ```
+/// use ferroid::time::{RtcClock, TWITTER_EPOCH, TimeSource, UNIX_EPOCH};
+///
+/// // 1.5 seconds after the Unix epoch on a 32.768 kHz RTC.
+/// let read = || (1_u64, 16_384_u32);
+///
+/// let clock = RtcClock::<_, 32_768>::new(read);
+/// assert_eq!(TimeSource::<u64>::current_millis(&clock), 1_500);
+///
+/// let coarse = RtcClock::<_, 32_768, 100>::with_epoch(read, UNIX_EPOCH);
+/// assert_eq!(TimeSource::<u64>::current_millis(&coarse), 15);
+///
+/// let before_epoch = RtcClock::<_, 32_768>::with_epoch(read, TWITTER_EPOCH);
+/// assert_eq!(TimeSource::<u64>::current_millis(&before_epoch), 0);
+/// ```
+///
+/// Both the tick rate and the granularity must be non-zero:
+///
+/// ```compile_fail
+/// use ferroid::time::RtcClock;
+///
+/// let _ = RtcClock::<_, 0>::new(|| (0_u64, 0_u32));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RtcClock<F, const TICKS_PER_SECOND: u32, const N: u64 = 1> {
+    read: F,
+    epoch_millis: u64,
+}
+
+impl<F, const TICKS_PER_SECOND: u32, const N: u64> RtcClock<F, TICKS_PER_SECOND, N>
+where
+    F: Fn() -> (u64, u32),
+{
+    const ASSERT_VALID_PARAMS: () = assert!(
+        TICKS_PER_SECOND > 0 && N > 0,
+        "RtcClock tick rate and granularity must be greater than zero"
+    );
+
+    /// Wraps `read`, using [`UNIX_EPOCH`] as the origin.
+    pub const fn new(read: F) -> Self {
+        Self::with_epoch(read, UNIX_EPOCH)
+    }
+
+    /// Wraps `read`, using `epoch` (a [`Duration`] since 1970-01-01 UTC) as
+    /// the origin.
+    pub const fn with_epoch(read: F, epoch: Duration) -> Self {
+        let () = Self::ASSERT_VALID_PARAMS;
+        #[allow(clippy::cast_possible_truncation)]
+        let epoch_millis = epoch.as_millis() as u64;
+        Self { read, epoch_millis }
+    }
+
+    fn units(&self) -> u64 {
+        let (seconds, subticks) = (self.read)();
+        let ticks_per_second = u64::from(TICKS_PER_SECOND);
+        let subticks = u64::from(subticks);
+        let seconds = seconds.saturating_add(subticks / ticks_per_second);
+        let sub_millis = (subticks % ticks_per_second) * 1_000 / ticks_per_second;
+        let millis = seconds.saturating_mul(1_000).saturating_add(sub_millis);
+        millis.saturating_sub(self.epoch_millis) / N
+    }
+}
+
+impl<F, const TICKS_PER_SECOND: u32, const N: u64> TimeSource<u64>
+    for RtcClock<F, TICKS_PER_SECOND, N>
+where
+    F: Fn() -> (u64, u32),
+{
+    const GRANULARITY_MILLIS: u64 = N;
+
+    fn current_millis(&self) -> u64 {
+        self.units()
+    }
+}
+
+impl<F, const TICKS_PER_SECOND: u32, const N: u64> TimeSource<u128>
+    for RtcClock<F, TICKS_PER_SECOND, N>
+where
+    F: Fn() -> (u64, u32),
+{
+    const GRANULARITY_MILLIS: u64 = N;
+
+    fn current_millis(&self) -> u128 {
+        u128::from(self.units())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn carries_overflowing_subticks_into_seconds() {
+        let clock = RtcClock::<_, 1_000>::new(|| (2, 2_500));
+        assert_eq!(TimeSource::<u64>::current_millis(&clock), 4_500);
+    }
+
+    #[test]
+    fn truncates_partial_milliseconds() {
+        // 32_767 of 32_768 ticks is 999.97ms.
+        let clock = RtcClock::<_, 32_768>::new(|| (0, 32_767));
+        assert_eq!(TimeSource::<u64>::current_millis(&clock), 999);
+    }
+}
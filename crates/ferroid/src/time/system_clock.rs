@@ -0,0 +1,123 @@
+use core::time::Duration;
+use std::time::SystemTime;
+
+use crate::time::{TimeSource, UNIX_EPOCH};
+
+/// A time source that reads [`SystemTime`] on every call.
+///
+/// Timestamps always match the wall clock, which keeps IDs from different
+/// hosts comparable and makes decoded datetimes exact. The trade-off is that
+/// the wall clock can be stepped backwards (NTP corrections, manual changes),
+/// in which case generators report [`Poll::Pending`] until it catches up.
+/// Readings before the epoch clamp to zero.
+///
+/// No background thread is used. `N` controls the number of real milliseconds
+/// represented by one returned time unit.
+///
+/// # Example
+/// ```
+/// use ferroid::{
+///     generator::BasicSnowflakeGenerator,
+///     id::SnowflakeTwitterId,
+///     time::{SystemClock, TWITTER_EPOCH},
+/// };
+///
+/// let generator: BasicSnowflakeGenerator<SnowflakeTwitterId, _> =
+///     BasicSnowflakeGenerator::new(0, SystemClock::<1>::with_epoch(TWITTER_EPOCH));
+/// let id = generator.next_id(|_| std::thread::yield_now());
+/// ```
+///
+/// The granularity must be non-zero:
+///
+/// ```compile_fail
+/// use ferroid::time::{SystemClock, UNIX_EPOCH};
+///
+/// let _ = SystemClock::<0>::with_epoch(UNIX_EPOCH);
+/// ```
+///
+/// [`Poll::Pending`]: crate::generator::Poll::Pending
+#[derive(Clone, Copy, Debug)]
+pub struct SystemClock<const N: u64 = 1> {
+    epoch: Duration,
+}
+
+impl Default for SystemClock<1> {
+    /// Constructs a system clock aligned to the default [`UNIX_EPOCH`].
+    fn default() -> Self {
+        Self::with_epoch(UNIX_EPOCH)
+    }
+}
+
+impl<const N: u64> SystemClock<N> {
+    const ASSERT_VALID_GRANULARITY: () =
+        assert!(N > 0, "SystemClock granularity must be greater than zero");
+    pub const GRANULARITY_MILLIS: u64 = N;
+
+    /// Constructs a system clock using `epoch` (a [`Duration`] since
+    /// 1970-01-01 UTC) as the origin.
+    #[must_use]
+    pub const fn with_epoch(epoch: Duration) -> Self {
+        let () = Self::ASSERT_VALID_GRANULARITY;
+        Self { epoch }
+    }
+}
+
+impl<const N: u64> TimeSource<u64> for SystemClock<N> {
+    const GRANULARITY_MILLIS: u64 = Self::GRANULARITY_MILLIS;
+
+    /// Returns the number of `N`-millisecond units since the configured epoch,
+    /// read from the wall clock.
+    fn current_millis(&self) -> u64 {
+        let () = Self::ASSERT_VALID_GRANULARITY;
+        let now = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+        #[allow(clippy::cast_possible_truncation)]
+        let millis = now.saturating_sub(self.epoch).as_millis() as u64;
+        millis / N
+    }
+}
+
+impl<const N: u64> TimeSource<u128> for SystemClock<N> {
+    const GRANULARITY_MILLIS: u64 = Self::GRANULARITY_MILLIS;
+
+    /// Returns the number of `N`-millisecond units since the configured epoch,
+    /// read from the wall clock.
+    fn current_millis(&self) -> u128 {
+        u128::from(<Self as TimeSource<u64>>::current_millis(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_wall_clock() {
+        let clock = SystemClock::default();
+        let before = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        let now = <SystemClock as TimeSource<u128>>::current_millis(&clock);
+        let after = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        assert!(before <= now && now <= after);
+    }
+
+    #[test]
+    fn clamps_before_epoch_and_quantizes() {
+        let future = SystemClock::<1>::with_epoch(Duration::from_secs(u64::MAX / 2));
+        assert_eq!(<SystemClock as TimeSource<u64>>::current_millis(&future), 0);
+
+        let coarse = SystemClock::<1_000>::with_epoch(UNIX_EPOCH);
+        let secs = <SystemClock<1_000> as TimeSource<u64>>::current_millis(&coarse);
+        let expected = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        assert!(secs.abs_diff(expected) <= 1);
+    }
+}